@@ -0,0 +1,170 @@
+//! Derive macros of the `adventofcode2024` crate, use them through `adventofcode2024::matrix`
+//! and `adventofcode2024::parser`.
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Ident, LitChar, LitStr};
+
+/// Implements `matrix::FromChar` for an enum whose unit variants are marked `#[char('#')]`.
+#[proc_macro_derive(FromChar, attributes(char))]
+pub fn derive_from_char(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input, |name, variants| {
+        let arms = variants
+            .iter()
+            .map(|(variant, c)| quote! { #c => ::core::option::Option::Some(#name::#variant), });
+        quote! {
+            impl ::adventofcode2024::matrix::FromChar for #name {
+                fn try_from_char(char: &char) -> ::core::option::Option<Self> {
+                    match char {
+                        #(#arms)*
+                        _ => ::core::option::Option::None,
+                    }
+                }
+            }
+        }
+    })
+}
+
+/// Implements `matrix::ToChar` for an enum whose unit variants are marked `#[char('#')]`.
+#[proc_macro_derive(ToChar, attributes(char))]
+pub fn derive_to_char(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input, |name, variants| {
+        let arms = variants
+            .iter()
+            .map(|(variant, c)| quote! { #name::#variant => #c, });
+        quote! {
+            impl ::adventofcode2024::matrix::ToChar for #name {
+                fn to_char(&self) -> char {
+                    match self {
+                        #(#arms)*
+                    }
+                }
+            }
+        }
+    })
+}
+
+/// Implements `parser::FromRegex` for a struct with a `#[regex(r"...")]` attribute.
+///
+/// Every field is parsed with `FromStr` from the named capture group of the same name, the regex
+/// is checked at compile time.
+#[proc_macro_derive(FromRegex, attributes(regex))]
+pub fn derive_from_regex(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match from_regex(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn from_regex(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    name,
+                    "only structs with named fields are supported",
+                ))
+            }
+        },
+        _ => return Err(Error::new_spanned(name, "only structs can be derived")),
+    };
+    let attr = input
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("regex"))
+        .ok_or_else(|| Error::new_spanned(name, "missing #[regex(r\"...\")] attribute"))?;
+    let pattern: LitStr = attr.parse_args()?;
+    let regex = regex::Regex::new(&pattern.value())
+        .map_err(|err| Error::new_spanned(&pattern, format!("invalid regex: {err}")))?;
+    let captures: Vec<&str> = regex.capture_names().flatten().collect();
+
+    let mut values = Vec::new();
+    for field in fields {
+        let ident = field.ident.as_ref().expect("named fields have a name");
+        let capture = ident.to_string();
+        if !captures.contains(&capture.as_str()) {
+            return Err(Error::new_spanned(
+                ident,
+                format!("the regex has no capture group named `{capture}`"),
+            ));
+        }
+        values.push(quote! { #ident: ::adventofcode2024::parser::capture(captures, #capture)? });
+    }
+    Ok(quote! {
+        impl ::adventofcode2024::parser::FromRegex for #name {
+            fn regex() -> &'static ::adventofcode2024::parser::Regex {
+                static REGEX: ::std::sync::OnceLock<::adventofcode2024::parser::Regex> = ::std::sync::OnceLock::new();
+                REGEX.get_or_init(|| ::adventofcode2024::parser::Regex::new(#pattern).expect("checked by the derive"))
+            }
+            fn from_captures(
+                captures: &::adventofcode2024::parser::Captures,
+            ) -> ::core::result::Result<Self, ::std::string::String> {
+                ::core::result::Result::Ok(Self { #(#values),* })
+            }
+        }
+    })
+}
+
+/// Collects the variants and their characters and generates the impl with `generate`.
+fn expand(
+    input: &DeriveInput,
+    generate: impl Fn(&Ident, &[(Ident, LitChar)]) -> TokenStream2,
+) -> TokenStream {
+    match char_variants(input) {
+        Ok(variants) => generate(&input.ident, &variants).into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// The unit variants of the enum with the character of their `#[char(..)]` attribute.
+///
+/// Every variant needs exactly one character and no character may be used twice.
+fn char_variants(input: &DeriveInput) -> syn::Result<Vec<(Ident, LitChar)>> {
+    let Data::Enum(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "only enums can be derived, mark each variant with #[char('.')]",
+        ));
+    };
+    let mut variants: Vec<(Ident, LitChar)> = Vec::new();
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new_spanned(
+                variant,
+                "only unit variants are supported",
+            ));
+        }
+        let mut chars = variant
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("char"));
+        let c: LitChar = match (chars.next(), chars.next()) {
+            (Some(attr), None) => attr.parse_args()?,
+            (None, _) => {
+                return Err(Error::new_spanned(
+                    variant,
+                    "missing #[char('.')] attribute",
+                ))
+            }
+            (Some(_), Some(extra)) => {
+                return Err(Error::new_spanned(extra, "duplicate #[char] attribute"))
+            }
+        };
+        if let Some((other, _)) = variants
+            .iter()
+            .find(|(_, known)| known.value() == c.value())
+        {
+            return Err(Error::new_spanned(
+                &c,
+                format!("{:?} is already used by `{other}`", c.value()),
+            ));
+        }
+        variants.push((variant.ident.clone(), c));
+    }
+    Ok(variants)
+}
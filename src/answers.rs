@@ -0,0 +1,102 @@
+use std::path::Path;
+
+use crate::error::{Error, Result};
+use crate::solution::Part;
+
+/// Name of the file holding the accepted answers, stored next to the inputs of a day.
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+/// The accepted answers of one input file, `None` where no answer has been accepted yet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expected {
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Expected {
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+}
+
+/// Accepted answers of a day, read from an `answers.txt` like
+///
+/// ```text
+/// # input           part1   part2
+/// input1.txt        1882714 19437052
+/// test_input1.txt   11      -
+/// ```
+///
+/// where `-` marks a part without an accepted answer and `#` starts a comment.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Answers {
+    pub entries: Vec<Expected>,
+}
+
+impl Answers {
+    pub fn parse(content: &str) -> Result<Self> {
+        let mut entries = Vec::new();
+        for (row, line) in content.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let [input, part1, part2] = fields[..] else {
+                return Err(Error::malformed(format!(
+                    "{ANSWERS_FILE} line {}: expected '<input> <part1> <part2>'",
+                    row + 1
+                )));
+            };
+            let answer = |value: &str| (value != "-").then(|| value.to_string());
+            entries.push(Expected {
+                input: input.to_string(),
+                part1: answer(part1),
+                part2: answer(part2),
+            });
+        }
+        Ok(Self { entries })
+    }
+    /// Loads the answers stored in `dir`, a missing file means no answers have been accepted.
+    pub fn load(dir: &Path) -> Result<Self> {
+        let path = dir.join(ANSWERS_FILE);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        Self::parse(&crate::util::read_input(&path)?)
+    }
+    pub fn for_input(&self, input: &str) -> Option<&Expected> {
+        self.entries.iter().find(|expected| expected.input == input)
+    }
+    pub fn expected(&self, input: &str, part: Part) -> Option<&str> {
+        self.for_input(input)
+            .and_then(|expected| expected.get(part))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(
+            "# input part1 part2
+input1.txt 1882714 19437052
+
+test_input1.txt 11 - # part 2 has a different example
+",
+        )
+        .unwrap();
+        assert_eq!(answers.entries.len(), 2);
+        assert_eq!(answers.expected("input1.txt", Part::Two), Some("19437052"));
+        assert_eq!(answers.expected("test_input1.txt", Part::One), Some("11"));
+        assert_eq!(answers.expected("test_input1.txt", Part::Two), None);
+        assert_eq!(answers.expected("input2.txt", Part::One), None);
+        assert!(Answers::parse("input1.txt 12").is_err());
+    }
+}
@@ -0,0 +1,396 @@
+use std::fmt::{self, Display};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+
+use crate::bench::{BenchRecord, Stage, Stats};
+use crate::error::{Error, Result};
+use crate::params::Params;
+use crate::util::read_input;
+
+/// Name of the file benchmark baselines are saved to, kept in the crate root.
+pub const BASELINE_FILE: &str = "bench_baselines.txt";
+
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(BASELINE_FILE)
+}
+
+/// Returns the short hash of the checked out commit, marked `-dirty` if there are local changes.
+pub fn git_revision() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(revision) if git(&["status", "--porcelain"]).is_some_and(|s| !s.is_empty()) => {
+            format!("{revision}-dirty")
+        }
+        Some(revision) => revision,
+        None => "unknown".to_string(),
+    }
+}
+
+/// The input a benchmark ran on, only results of the same input are compared.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputKey {
+    /// The name of the input file, `-` for stdin.
+    pub input: String,
+    /// The parameters the input was solved with as `name=value` list, `-` if the day has none.
+    pub params: String,
+}
+
+impl InputKey {
+    /// The key of the input named `input`, `None` for stdin, solved with `params`.
+    pub fn new(input: Option<&str>, params: &Params) -> Self {
+        // fields of the baseline file are separated by whitespace
+        let field = |value: &str| value.replace(char::is_whitespace, "_");
+        let params: Vec<String> = params
+            .iter()
+            .map(|(name, value)| field(&format!("{name}={value}")))
+            .collect();
+        Self {
+            input: field(input.unwrap_or("-")),
+            params: if params.is_empty() {
+                "-".to_string()
+            } else {
+                params.join(",")
+            },
+        }
+    }
+}
+
+/// A benchmark result saved for a revision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Baseline {
+    pub revision: String,
+    pub key: InputKey,
+    pub record: BenchRecord,
+}
+
+/// Saved benchmark results, one line per revision, day, input, parameters and stage:
+///
+/// ```text
+/// # revision day input params stage samples min_ns median_ns p95_ns max_ns stddev_ns
+/// 1a2b3c4 14 input1.txt rows=103,cols=101 part1 100 132440 150280 155440 160020 7610
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Baselines {
+    pub entries: Vec<Baseline>,
+}
+
+const HEADER: &str =
+    "# revision day input params stage samples min_ns median_ns p95_ns max_ns stddev_ns";
+
+impl Baselines {
+    pub fn parse(content: &str) -> Result<Self> {
+        let mut entries = Vec::new();
+        for (row, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let invalid = |reason: String| {
+                Error::malformed(format!("{BASELINE_FILE} line {}: {reason}", row + 1))
+            };
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let [revision, day, input, params, stage, samples, min, median, p95, max, stddev] =
+                fields[..]
+            else {
+                return Err(invalid(format!(
+                    "expected 11 fields, found {}",
+                    fields.len()
+                )));
+            };
+            let number = |value: &str| {
+                value
+                    .parse::<u64>()
+                    .map_err(|_| invalid(format!("invalid number '{value}'")))
+            };
+            let nanos = |value: &str| number(value).map(Duration::from_nanos);
+            let record = BenchRecord {
+                day: number(day)? as i32,
+                stage: stage.parse().map_err(invalid)?,
+                stats: Stats {
+                    samples: number(samples)? as usize,
+                    min: nanos(min)?,
+                    median: nanos(median)?,
+                    p95: nanos(p95)?,
+                    max: nanos(max)?,
+                    stddev: nanos(stddev)?,
+                },
+            };
+            entries.push(Baseline {
+                revision: revision.to_string(),
+                key: InputKey {
+                    input: input.to_string(),
+                    params: params.to_string(),
+                },
+                record,
+            });
+        }
+        Ok(Self { entries })
+    }
+    /// Loads the baselines at `path`, a missing file holds no baselines.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        Self::parse(&read_input(path)?)
+    }
+    pub fn save(&self, path: &Path) -> Result<()> {
+        std::fs::write(path, self.to_string()).map_err(|source| Error::WriteFailed {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+    /// Stores `records` for `revision`, replacing results saved earlier for the same revision
+    /// and input.
+    pub fn record(&mut self, revision: &str, records: &[(InputKey, BenchRecord)]) {
+        self.entries.retain(|entry| {
+            entry.revision != revision
+                || !records.iter().any(|(key, record)| {
+                    (record.day, record.stage, key)
+                        == (entry.record.day, entry.record.stage, &entry.key)
+                })
+        });
+        self.entries
+            .extend(records.iter().map(|(key, record)| Baseline {
+                revision: revision.to_string(),
+                key: key.clone(),
+                record: record.clone(),
+            }));
+    }
+    /// The most recently saved result of a stage on the input `key`, optionally restricted to
+    /// one revision.
+    pub fn latest(
+        &self,
+        day: i32,
+        stage: Stage,
+        key: &InputKey,
+        revision: Option<&str>,
+    ) -> Option<&Baseline> {
+        self.entries.iter().rev().find(|entry| {
+            (entry.record.day, entry.record.stage, &entry.key) == (day, stage, key)
+                && revision.is_none_or(|revision| entry.revision == revision)
+        })
+    }
+}
+
+impl Display for Baselines {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{HEADER}")?;
+        for Baseline {
+            revision,
+            key,
+            record,
+        } in &self.entries
+        {
+            let stats = &record.stats;
+            writeln!(
+                f,
+                "{revision} {} {} {} {} {} {} {} {} {} {}",
+                record.day,
+                key.input,
+                key.params,
+                record.stage,
+                stats.samples,
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.p95.as_nanos(),
+                stats.max.as_nanos(),
+                stats.stddev.as_nanos()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Median of a new benchmark result next to the median of its baseline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub day: i32,
+    pub stage: Stage,
+    pub revision: String,
+    pub baseline: Duration,
+    pub median: Duration,
+    /// Whether the median got slower by more than the allowed threshold.
+    pub regressed: bool,
+}
+
+impl Comparison {
+    /// Relative change of the median in percent, positive if it got slower.
+    pub fn change(&self) -> f64 {
+        let baseline = self.baseline.as_secs_f64();
+        if baseline == 0.0 {
+            return 0.0;
+        }
+        (self.median.as_secs_f64() / baseline - 1.0) * 100.0
+    }
+}
+
+pub const COMPARISON_HEADER: &str = "day  stage    baseline     median    change";
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:>3}  {:<5}  {:>10.2?} {:>10.2?} {:>+8.1}%  vs {}",
+            self.day,
+            self.stage,
+            self.baseline,
+            self.median,
+            self.change(),
+            self.revision
+        )?;
+        if self.regressed {
+            write!(f, "  REGRESSION")?;
+        }
+        Ok(())
+    }
+}
+
+/// Compares `records` with their baselines on the same input, records without a saved baseline
+/// are skipped.
+///
+/// A record regressed if its median is more than `threshold` percent slower than the baseline.
+/// Fails if `against` names a revision without baselines or none of the records has one, so a
+/// comparison never passes without comparing anything.
+pub fn compare(
+    records: &[(InputKey, BenchRecord)],
+    baselines: &Baselines,
+    against: Option<&str>,
+    threshold: f64,
+) -> Result<Vec<Comparison>> {
+    let no_baseline = || Error::NoBaseline {
+        revision: against.map(str::to_string),
+    };
+    if against.is_some_and(|revision| baselines.entries.iter().all(|e| e.revision != revision)) {
+        return Err(no_baseline());
+    }
+    let comparisons: Vec<Comparison> = records
+        .iter()
+        .filter_map(|(key, record)| {
+            let baseline = baselines.latest(record.day, record.stage, key, against)?;
+            let mut comparison = Comparison {
+                day: record.day,
+                stage: record.stage,
+                revision: baseline.revision.clone(),
+                baseline: baseline.record.stats.median,
+                median: record.stats.median,
+                regressed: false,
+            };
+            comparison.regressed = comparison.change() > threshold;
+            Some(comparison)
+        })
+        .collect();
+    if comparisons.is_empty() && !records.is_empty() {
+        return Err(no_baseline());
+    }
+    Ok(comparisons)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::solution::Part;
+
+    fn key(input: &str) -> InputKey {
+        InputKey::new(Some(input), &Params::new(&[("rows", "7"), ("cols", "11")]))
+    }
+
+    fn record(day: i32, stage: Stage, median_us: u64) -> (InputKey, BenchRecord) {
+        let median = Duration::from_micros(median_us);
+        let record = BenchRecord {
+            day,
+            stage,
+            stats: Stats {
+                samples: 10,
+                min: median,
+                median,
+                p95: median,
+                max: median,
+                stddev: Duration::ZERO,
+            },
+        };
+        (key("input1.txt"), record)
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let mut baselines = Baselines::default();
+        baselines.record(
+            "abc",
+            &[
+                record(1, Stage::Parse, 5),
+                record(1, Stage::Solve(Part::One), 50),
+            ],
+        );
+        baselines.record("def", &[record(1, Stage::Solve(Part::One), 60)]);
+        baselines.record("def", &[record(1, Stage::Solve(Part::One), 70)]);
+        assert_eq!(baselines.entries.len(), 3);
+        assert_eq!(Baselines::parse(&baselines.to_string()).unwrap(), baselines);
+
+        let part1 = Stage::Solve(Part::One);
+        let input = key("input1.txt");
+        assert_eq!(input.params, "rows=7,cols=11");
+        let latest = baselines.latest(1, part1, &input, None).unwrap();
+        assert_eq!(
+            (latest.revision.as_str(), latest.record.stats.median),
+            ("def", Duration::from_micros(70))
+        );
+        assert_eq!(
+            baselines
+                .latest(1, part1, &input, Some("abc"))
+                .unwrap()
+                .revision,
+            "abc"
+        );
+        assert!(baselines.latest(2, part1, &input, None).is_none());
+        assert!(baselines
+            .latest(1, part1, &key("input2.txt"), None)
+            .is_none());
+        let stdin = InputKey::new(None, &Params::new(&[]));
+        assert_eq!((stdin.input.as_str(), stdin.params.as_str()), ("-", "-"));
+    }
+
+    #[test]
+    fn test_compare() {
+        let mut baselines = Baselines::default();
+        baselines.record(
+            "abc",
+            &[
+                record(1, Stage::Parse, 100),
+                record(1, Stage::Solve(Part::One), 100),
+            ],
+        );
+        let records = [
+            record(1, Stage::Parse, 109),
+            record(1, Stage::Solve(Part::One), 111),
+            record(2, Stage::Parse, 500),
+        ];
+        let comparisons = compare(&records, &baselines, None, 10.0).unwrap();
+        assert_eq!(comparisons.len(), 2);
+        assert!(!comparisons[0].regressed);
+        assert!(comparisons[1].regressed);
+        assert_eq!(
+            compare(&records, &baselines, Some("abc"), 10.0).unwrap(),
+            comparisons
+        );
+        let mut other = record(1, Stage::Parse, 500);
+        other.0 = key("test_input1.txt");
+        assert!(matches!(
+            compare(&[other], &baselines, None, 10.0),
+            Err(Error::NoBaseline { revision: None })
+        ));
+        assert!(matches!(
+            compare(&records, &baselines, Some("def"), 10.0),
+            Err(Error::NoBaseline { revision: Some(_) })
+        ));
+        assert!(compare(&records, &Baselines::default(), None, 10.0).is_err());
+    }
+}
@@ -0,0 +1,223 @@
+use std::fmt::{self, Display};
+use std::hint::black_box;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::error::Result;
+use crate::params::Params;
+use crate::solution::{Part, Puzzle};
+
+/// Measurements taken even if the time budget is exceeded, so slow parts still get statistics.
+const MIN_SAMPLES: usize = 5;
+
+/// How long and how often every stage of a day is run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchOptions {
+    /// Time spent running a stage before measuring it, it is always run at least once.
+    pub warmup: Duration,
+    /// Maximum number of measured runs per stage.
+    pub samples: usize,
+    /// Time after which a stage stops sampling once it has at least a few samples.
+    pub budget: Duration,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            warmup: Duration::from_millis(200),
+            samples: 100,
+            budget: Duration::from_secs(3),
+        }
+    }
+}
+
+/// The measured part of a day: parsing its input or solving one part of the parsed input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Stage {
+    Parse,
+    Solve(Part),
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => f.pad("parse"),
+            Stage::Solve(part) => f.pad(&format!("part{}", part.number())),
+        }
+    }
+}
+
+impl FromStr for Stage {
+    type Err = String;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Stage::Parse),
+            "part1" => Ok(Stage::Solve(Part::One)),
+            "part2" => Ok(Stage::Solve(Part::Two)),
+            other => Err(format!("unknown stage '{other}'")),
+        }
+    }
+}
+
+/// Summary of the run times of one stage.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub max: Duration,
+    /// Standard deviation of the samples.
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Summarizes a non-empty list of run times.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "statistics need at least one sample");
+        samples.sort();
+        let n = samples.len();
+        // nearest rank percentiles
+        let percentile = |p: usize| samples[(n * p).div_ceil(100).max(1) - 1];
+        let secs: Vec<f64> = samples.iter().map(Duration::as_secs_f64).collect();
+        let mean = secs.iter().sum::<f64>() / n as f64;
+        let variance = secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n as f64;
+        Self {
+            samples: n,
+            min: samples[0],
+            median: percentile(50),
+            p95: percentile(95),
+            max: samples[n - 1],
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Benchmark result of one stage of a day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchRecord {
+    pub day: i32,
+    pub stage: Stage,
+    pub stats: Stats,
+}
+
+pub const TABLE_HEADER: &str = "day  stage  samples        min     median        p95     stddev";
+
+/// Formats the record as a row below [`TABLE_HEADER`].
+impl Display for BenchRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Stats {
+            samples,
+            min,
+            median,
+            p95,
+            stddev,
+            ..
+        } = self.stats;
+        write!(
+            f,
+            "{:>3}  {:<5}  {samples:>7} {min:>10.2?} {median:>10.2?} {p95:>10.2?} {stddev:>10.2?}",
+            self.day, self.stage
+        )
+    }
+}
+
+impl BenchRecord {
+    pub fn to_json(&self) -> String {
+        let Stats {
+            samples,
+            min,
+            median,
+            p95,
+            max,
+            stddev,
+        } = self.stats;
+        format!(
+            r#"{{"day": {}, "stage": "{}", "samples": {samples}, "min_ns": {}, "median_ns": {}, "p95_ns": {}, "max_ns": {}, "stddev_ns": {}}}"#,
+            self.day,
+            self.stage,
+            min.as_nanos(),
+            median.as_nanos(),
+            p95.as_nanos(),
+            max.as_nanos(),
+            stddev.as_nanos()
+        )
+    }
+}
+
+/// Formats the records as a JSON array with one object per line.
+pub fn to_json(records: &[BenchRecord]) -> String {
+    let rows: Vec<String> = records
+        .iter()
+        .map(|record| format!("  {}", record.to_json()))
+        .collect();
+    format!("[\n{}\n]", rows.join(",\n"))
+}
+
+/// Warms up and then repeatedly runs `f`, stopping at the first error.
+fn measure<T>(options: &BenchOptions, mut f: impl FnMut() -> Result<T>) -> Result<Stats> {
+    let start = Instant::now();
+    loop {
+        black_box(f()?);
+        if start.elapsed() >= options.warmup {
+            break;
+        }
+    }
+    let mut samples = Vec::new();
+    let start = Instant::now();
+    while samples.len() < options.samples.max(1)
+        && (samples.len() < MIN_SAMPLES || start.elapsed() < options.budget)
+    {
+        let run = Instant::now();
+        black_box(f()?);
+        samples.push(run.elapsed());
+    }
+    Ok(Stats::from_samples(samples))
+}
+
+/// Times parsing `content` and solving each of `parts` on the parsed input.
+pub fn bench_puzzle<P: Puzzle>(
+    parts: &[Part],
+    content: &str,
+    params: &Params,
+    options: &BenchOptions,
+) -> Result<Vec<BenchRecord>> {
+    let record = |stage, stats| BenchRecord {
+        day: P::DAY,
+        stage,
+        stats,
+    };
+    let mut records = vec![record(
+        Stage::Parse,
+        measure(options, || P::parse_with(content, params))?,
+    )];
+    let input = P::parse_with(content, params)?;
+    for &part in parts {
+        let stats = measure(options, || match part {
+            Part::One => P::part1(black_box(&input)),
+            Part::Two => P::part2(black_box(&input)),
+        })?;
+        records.push(record(Stage::Solve(part), stats));
+    }
+    Ok(records)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples = (1..=100).rev().map(Duration::from_millis).collect();
+        let stats = Stats::from_samples(samples);
+        assert_eq!(stats.samples, 100);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(50));
+        assert_eq!(stats.p95, Duration::from_millis(95));
+        assert_eq!(stats.max, Duration::from_millis(100));
+
+        let stats = Stats::from_samples(vec![Duration::from_micros(7)]);
+        assert_eq!((stats.median, stats.p95), (stats.min, stats.max));
+        assert_eq!(stats.stddev, Duration::ZERO);
+    }
+}
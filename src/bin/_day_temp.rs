@@ -1,25 +1,54 @@
-use adventofcode2024::util;
-fn part1(content: &str) -> i64 {
-    let solution = 0;
-    solution
+use adventofcode2024::{
+    error::Result,
+    solution::{Answer, Puzzle},
+};
+
+pub struct DayTemp;
+
+impl Puzzle for DayTemp {
+    const DAY: i32 = 0;
+    type Input = Vec<String>;
+
+    fn parse(content: &str) -> Result<Self::Input> {
+        parse(content)
+    }
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        part1(input).map(Answer::from)
+    }
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        part2(input).map(Answer::from)
+    }
+}
+
+pub fn parse(content: &str) -> Result<Vec<String>> {
+    Ok(content.lines().map(str::to_string).collect())
+}
+pub fn part1(_input: &[String]) -> Result<i64> {
+    Ok(0)
 }
-fn part2(content: &str) -> i64 {
-    let solution = 0;
-    solution
+pub fn part2(_input: &[String]) -> Result<i64> {
+    Ok(0)
 }
 
 fn main() {
-    let content =
-        util::load_file(util::get_day(), 1, false).expect("failed to load input text file");
-    let solution = part1(&content);
-    dbg!(solution);
-    let solution = part2(&content);
-    dbg!(solution);
+    use adventofcode2024::{runner, util::InputSource};
+    if let Err(err) = runner::run(&DayTemp, None, &InputSource::default(), &[]) {
+        eprintln!("{err}");
+    }
 }
 
 #[cfg(test)]
 mod test {
+    use super::*;
 
+    const TEST1: &str = "";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(TEST1).unwrap()).unwrap(), 0);
+    }
     #[test]
-    fn test_1() {}
+    fn test_part2() {
+        assert_eq!(part2(&parse(TEST1).unwrap()).unwrap(), 0);
+    }
 }
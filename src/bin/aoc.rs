@@ -0,0 +1,8 @@
+use std::process::ExitCode;
+
+use adventofcode2024::runner;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    runner::main_with_args(&args)
+}
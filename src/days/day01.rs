@@ -0,0 +1,66 @@
+use std::collections::HashMap;
+
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Puzzle};
+use crate::util::parse_number;
+
+pub struct Day01;
+
+impl Puzzle for Day01 {
+    const DAY: i32 = 1;
+    type Input = (Vec<i32>, Vec<i32>);
+
+    fn parse(content: &str) -> Result<Self::Input> {
+        parse(content)
+    }
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+/// Parses the left and right location list.
+pub fn parse(content: &str) -> Result<(Vec<i32>, Vec<i32>)> {
+    let mut left = Vec::new();
+    let mut right = Vec::new();
+
+    for l in content.lines().filter(|l| !l.trim().is_empty()) {
+        let (a, b) = l
+            .split_once("   ")
+            .ok_or_else(|| Error::malformed(format!("expected two locations in '{l}'")))?;
+        left.push(parse_number(a.trim())?);
+        right.push(parse_number(b.trim())?);
+    }
+    Ok((left, right))
+}
+
+pub fn part1((left, right): &(Vec<i32>, Vec<i32>)) -> u32 {
+    let mut left = left.clone();
+    let mut right = right.clone();
+
+    left.sort();
+    right.sort();
+
+    let mut solution = 0;
+    for (l, r) in left.iter().zip(right.iter()) {
+        solution += l.abs_diff(*r);
+    }
+    solution
+}
+
+pub fn part2((left, right): &(Vec<i32>, Vec<i32>)) -> i32 {
+    let mut count_map = HashMap::<i32, i32>::new();
+
+    for r in right {
+        // get the entry for the right hand side and increase  the occurance count or insert 1
+        count_map.entry(*r).and_modify(|val| *val += 1).or_insert(1);
+    }
+    let mut solution = 0;
+
+    for l in left {
+        solution += l * count_map.get(l).unwrap_or(&0);
+    }
+    solution
+}
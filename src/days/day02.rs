@@ -0,0 +1,135 @@
+use crate::error::Result;
+use crate::solution::{Answer, Puzzle};
+use crate::util::parse_number;
+
+pub struct Day02;
+
+impl Puzzle for Day02 {
+    const DAY: i32 = 2;
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(content: &str) -> Result<Self::Input> {
+        parse(content)
+    }
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+/// Parses one report of levels per line.
+pub fn parse(content: &str) -> Result<Vec<Vec<i32>>> {
+    content
+        .lines()
+        .filter(|l| !l.is_empty())
+        .map(|l| l.split(" ").map(parse_number).collect())
+        .collect()
+}
+
+fn is_safe(line: &[i32]) -> bool {
+    if line.len() <= 1 {
+        return true;
+    }
+    let mut a = line[0];
+    let diff0 = (line[1] - a).signum();
+
+    for b in line.iter().skip(1) {
+        let diff = b - a;
+        if diff.abs() > 3 || diff.abs() == 0 || diff.signum() != diff0 {
+            return false;
+        }
+        a = *b;
+    }
+    true
+}
+fn is_safe2(line: &[i32], skip: Option<usize>) -> bool {
+    let mut a: Option<i32> = None;
+    let mut dir0: Option<i32> = None;
+    let mut diff;
+    for (idx, b) in line.iter().enumerate() {
+        if skip == Some(idx) {
+            // we skip in case skip is set
+            continue;
+        }
+
+        if let Some(a) = a {
+            diff = b - a;
+        } else {
+            a = Some(*b);
+            continue;
+        }
+        let dir = dir0.get_or_insert_with(|| diff.signum());
+
+        if diff.abs() > 3 || diff.abs() == 0 || diff.signum() != *dir {
+            if skip.is_some() {
+                return false;
+            }
+            return is_safe2(line, Some(idx - 1))
+                || is_safe2(line, Some(idx))
+                || is_safe2(line, Some(0));
+        }
+        a = Some(*b);
+    }
+    true
+}
+pub fn part1(reports: &[Vec<i32>]) -> i32 {
+    let mut solution = 0;
+    for line in reports {
+        if is_safe(line) {
+            solution += 1;
+        }
+    }
+    solution
+}
+
+pub fn part2(reports: &[Vec<i32>]) -> i32 {
+    let mut solution = 0;
+    for line in reports {
+        if is_safe2(line, None) {
+            solution += 1;
+        }
+    }
+    solution
+}
+fn is_safe_corr(line: &[i32]) -> bool {
+    for index in 0..line.len() {
+        let mut nline = line.to_vec();
+        nline.remove(index);
+
+        if is_safe(&nline) {
+            return true;
+        }
+    }
+    false
+}
+pub fn part2corr(reports: &[Vec<i32>]) -> i32 {
+    let mut solution = 0;
+    for line in reports {
+        if is_safe_corr(line) {
+            solution += 1;
+        }
+    }
+    solution
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST1: &str = "7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9";
+
+    #[test]
+    fn test_parts() {
+        let reports = parse(TEST1).unwrap();
+        assert_eq!(part1(&reports), 2);
+        assert_eq!(part2(&reports), 4);
+        assert_eq!(part2corr(&reports), 4);
+    }
+}
@@ -0,0 +1,85 @@
+use crate::error::Result;
+use crate::solution::{Answer, Puzzle};
+use crate::tokenizer::{literal, pattern, Tokenizer};
+
+pub struct Day03;
+
+impl Puzzle for Day03 {
+    const DAY: i32 = 3;
+    type Input = Vec<Tokens>;
+
+    fn parse(content: &str) -> Result<Self::Input> {
+        Ok(parse(content))
+    }
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+pub fn part1(tokens: &[Tokens]) -> i32 {
+    let mut solution = 0;
+    for token in tokens {
+        if let Tokens::Mul(a, b) = token {
+            solution += a * b;
+        }
+    }
+    solution
+}
+#[derive(Debug, Clone)]
+pub enum Tokens {
+    Do,
+    Dont,
+    Mul(i32, i32),
+}
+
+impl Tokens {
+    /// The instructions that can appear within the corrupted memory.
+    fn tokenizer<'a>() -> Tokenizer<'a, Self> {
+        Tokenizer::new()
+            .with(literal("do()", Tokens::Do))
+            .with(literal("don't()", Tokens::Dont))
+            .with(pattern(r"mul\(([0-9]{1,3}),([0-9]{1,3})\)", |cap| {
+                Some(Tokens::Mul(cap[1].parse().ok()?, cap[2].parse().ok()?))
+            }))
+    }
+}
+
+/// Finds all instructions in the corrupted memory, ordered by their position.
+pub fn parse(content: &str) -> Vec<Tokens> {
+    Tokens::tokenizer()
+        .tokenize(content)
+        .map(|(_, token)| token)
+        .collect()
+}
+
+pub fn part2(tokens: &[Tokens]) -> i32 {
+    let mut solution = 0;
+    let mut enabled = true;
+    for token in tokens {
+        let (next, change) = match (enabled, token) {
+            (true, Tokens::Mul(a, b)) => (true, a * b),
+            (true, Tokens::Dont) => (false, 0),
+            (false, Tokens::Do) => (true, 0),
+            _ => (enabled, 0),
+        };
+        solution += change;
+        enabled = next;
+    }
+    solution
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parts() {
+        let tokens =
+            parse("xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))");
+        assert_eq!(part1(&tokens), 161);
+        assert_eq!(part2(&tokens), 48);
+    }
+}
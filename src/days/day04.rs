@@ -0,0 +1,199 @@
+use std::iter;
+
+use crate::error::Result;
+use crate::matrix::{FromChar, Grid, Matrix, MatrixIdx, MatrixIdxOffset, ToChar};
+use crate::solution::{Answer, Puzzle};
+
+pub struct Day04;
+
+impl Puzzle for Day04 {
+    const DAY: i32 = 4;
+    type Input = Matrix<XmasItems>;
+
+    fn parse(content: &str) -> Result<Self::Input> {
+        parse(content)
+    }
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, FromChar, ToChar)]
+pub enum XmasItems {
+    #[char('X')]
+    X,
+    #[char('M')]
+    M,
+    #[char('A')]
+    A,
+    #[char('S')]
+    S,
+}
+impl XmasItems {
+    fn next(&self) -> Option<XmasItems> {
+        use XmasItems::*;
+        match self {
+            X => Some(M),
+            M => Some(A),
+            A => Some(S),
+            S => None,
+        }
+    }
+}
+
+//#[derive(Debug)]
+//struct XmasSearcher {
+//    dir: Option<MatrixIdxOffset>,
+//    next_item: XmasItems,
+//    count: usize,
+//}
+//impl Default for XmasSearcher {
+//    fn default() -> Self {
+//        XmasSearcher {
+//            dir: None,
+//            next_item: XmasItems::X,
+//            count: 0,
+//        }
+//    }
+//}
+//    fn traverse(
+//        &mut self,
+//        graph: &Matrix<XmasItems>,
+//        node_id: &<Matrix<XmasItems> as Graph>::NodeIdT,
+//        edge: &<Matrix<XmasItems> as Graph>::EdgeT,
+//    ) -> bool;
+//}
+//
+//trait Graph: Sized {
+//    type EdgeT;
+//    type NodeIdT;
+//    fn next(&self, node_id: &Self::NodeIdT, edge: &Self::EdgeT) -> Option<Self::NodeIdT>;
+//    fn edges(&self, node_id: &Self::NodeIdT) -> Vec<&Self::EdgeT>;
+//    fn traverse<T: GraphTraverselStrategy>(&self, strategy: T) {}
+//}
+
+const DIRECTIONS: [MatrixIdxOffset; 8] = [
+    MatrixIdxOffset::new(0, -1),
+    MatrixIdxOffset::new(0, 1),
+    MatrixIdxOffset::new(1, -1),
+    MatrixIdxOffset::new(1, 0),
+    MatrixIdxOffset::new(1, 1),
+    MatrixIdxOffset::new(-1, -1),
+    MatrixIdxOffset::new(-1, 0),
+    MatrixIdxOffset::new(-1, 1),
+];
+//impl Graph for Matrix<XmasItems> {
+//    type EdgeT = MatrixIdxOffset;
+//    type NodeIdT = MatrixIdx;
+//    fn next(&self, start: &Self::NodeIdT, edge: &Self::EdgeT) -> Option<Self::NodeIdT> {
+//        let nidx = start + edge;
+//        if self.is_valid_idx(&nidx) {
+//            Some(nidx)
+//        } else {
+//            None
+//        }
+//    }
+//    fn edges(&self, node_id: &Self::NodeIdT) -> Vec<&MatrixIdxOffset> {
+//        let iter = DIRECTIONS.iter();
+//        iter.filter_map(|d| {
+//            let nidx = node_id + d;
+//            if self.is_valid_idx(&nidx) {
+//                Some(d)
+//            } else {
+//                None
+//            }
+//        })
+//        .collect()
+//    }
+//}
+
+/// Whether the items from `expected` to `S` follow `start` in `direction`.
+fn check_xmas(
+    matrix: &Matrix<XmasItems>,
+    expected: XmasItems,
+    start: MatrixIdx,
+    direction: MatrixIdxOffset,
+) -> bool {
+    let mut ray = matrix.ray(start, direction).skip(1);
+    iter::successors(Some(expected), XmasItems::next)
+        .all(|expected| ray.next().is_some_and(|(_, item)| *item == expected))
+}
+/// Whether `MAS` is written through the `A` at `idx` in `direction`.
+fn check_mas(matrix: &Matrix<XmasItems>, idx: MatrixIdx, direction: MatrixIdxOffset) -> bool {
+    let item = |idx: Option<MatrixIdx>| idx.and_then(|idx| matrix.get(&idx));
+    item(idx.checked_sub(&direction)) == Some(&XmasItems::M)
+        && item(idx.checked_add(&direction)) == Some(&XmasItems::S)
+}
+pub fn parse(content: &str) -> Result<Matrix<XmasItems>> {
+    Matrix::try_from_str(content)
+}
+pub fn part1(matrix: &Matrix<XmasItems>) -> i32 {
+    let mut solution = 0;
+    for (idx, value) in matrix.idx_value_iter() {
+        if value == &XmasItems::X {
+            let expected = XmasItems::M;
+            for direction in DIRECTIONS {
+                if check_xmas(matrix, expected, idx, direction) {
+                    solution += 1;
+                }
+            }
+        }
+    }
+    solution
+}
+pub fn part2(matrix: &Matrix<XmasItems>) -> i32 {
+    let mut solution = 0;
+    for (idx, value) in matrix.idx_value_iter() {
+        if value == &XmasItems::A {
+            let down_diag_mas = check_mas(matrix, idx, MatrixIdxOffset::new(-1, -1))
+                || check_mas(matrix, idx, MatrixIdxOffset::new(1, 1));
+            let up_diag_mas = check_mas(matrix, idx, MatrixIdxOffset::new(1, -1))
+                || check_mas(matrix, idx, MatrixIdxOffset::new(-1, 1));
+            if up_diag_mas && down_diag_mas {
+                solution += 1;
+            }
+        }
+    }
+    solution
+}
+#[cfg(test)]
+mod test {
+    use crate::matrix::Matrix;
+
+    use super::*;
+    #[test]
+    fn test_xmas_check() {
+        // XMASS
+        // MMSAA
+        // ASAMM
+        // SXMSX
+        let matrix = Matrix::<XmasItems>::try_from_str("XMASS\nMMSAA\nASAMM\nSXMSX").unwrap();
+        assert!(check_xmas(
+            &matrix,
+            XmasItems::M,
+            MatrixIdx::new(0, 0),
+            MatrixIdxOffset::new(0, 1)
+        ));
+        assert!(check_xmas(
+            &matrix,
+            XmasItems::M,
+            MatrixIdx::new(0, 0),
+            MatrixIdxOffset::new(1, 1)
+        ));
+        assert!(check_xmas(
+            &matrix,
+            XmasItems::M,
+            MatrixIdx::new(0, 0),
+            MatrixIdxOffset::new(1, 0)
+        ));
+        assert!(check_xmas(
+            &matrix,
+            XmasItems::M,
+            MatrixIdx::new(3, 4),
+            MatrixIdxOffset::new(-1, 0)
+        ));
+    }
+}
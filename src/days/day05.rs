@@ -0,0 +1,98 @@
+use crate::error::Result;
+use crate::parser::{self, int, lines, list, pair, sections, Located};
+use crate::solution::{Answer, Puzzle};
+
+pub struct Day05;
+
+impl Puzzle for Day05 {
+    const DAY: i32 = 5;
+    type Input = (Vec<PageRule>, Vec<Vec<i32>>);
+
+    fn parse(content: &str) -> Result<Self::Input> {
+        parse(content)
+    }
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+#[derive(Debug)]
+pub struct PageRule {
+    before: i32,
+    after: i32,
+}
+impl PageRule {
+    fn new(before: i32, after: i32) -> Self {
+        Self { before, after }
+    }
+    /// Parses a rule like `47|53`.
+    fn parse(input: Located) -> Result<Self> {
+        let (before, after) = pair("|", int, int)(input)?;
+        Ok(PageRule::new(before, after))
+    }
+    fn check(&self, input: &[i32]) -> bool {
+        let mut found_after = false;
+        for num in input {
+            if num == &self.before {
+                return !found_after;
+            }
+            if num == &self.after {
+                found_after = true;
+            }
+        }
+        true
+    }
+    /// fixes the input slice by swapping the two elements if the rule demands it
+    fn fix(&self, input: &mut [i32]) -> bool {
+        let mut found_after = None;
+        let mut found_before = None;
+        for (index, num) in input.iter().enumerate() {
+            if num == &self.before {
+                found_before = Some(index);
+                break;
+            }
+            if num == &self.after {
+                found_after = Some(index);
+            }
+        }
+        if let (Some(before), Some(after)) = (found_before, found_after) {
+            input.swap(before, after);
+            return true;
+        }
+        false
+    }
+}
+pub fn parse(content: &str) -> Result<(Vec<PageRule>, Vec<Vec<i32>>)> {
+    parser::parse(
+        content,
+        sections(lines(PageRule::parse), lines(list(",", int))),
+    )
+}
+
+pub fn part1((rules, updates): &(Vec<PageRule>, Vec<Vec<i32>>)) -> i32 {
+    let mut solution = 0;
+    for page_list in updates {
+        if rules.iter().all(|rule| rule.check(page_list)) {
+            solution += page_list[page_list.len() / 2];
+        }
+    }
+    solution
+}
+pub fn part2((rules, updates): &(Vec<PageRule>, Vec<Vec<i32>>)) -> i32 {
+    let mut solution = 0;
+    let mut to_fix = Vec::new();
+    for page_list in updates {
+        if rules.iter().any(|rule| !rule.check(page_list)) {
+            // here we try to fix the rule by iterating until all rules are satisfied
+            to_fix.push(page_list.clone());
+        }
+    }
+    for page_list in to_fix.iter_mut() {
+        while rules.iter().any(|rule| rule.fix(page_list)) {}
+        solution += page_list[page_list.len() / 2];
+    }
+    solution
+}
@@ -0,0 +1,185 @@
+use crate::error::{Error, Result};
+use crate::matrix::{BitGrid, Direction, DirectionSet, FromChar, Matrix, MatrixIdx, ToChar};
+use crate::solution::{Answer, Puzzle};
+
+pub struct Day06;
+
+impl Puzzle for Day06 {
+    const DAY: i32 = 6;
+    type Input = (Matrix<LabTile>, MatrixIdx);
+
+    fn parse(content: &str) -> Result<Self::Input> {
+        parse(content)
+    }
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).0.into())
+    }
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, FromChar, ToChar)]
+pub enum LabTile {
+    #[char('.')]
+    Tile,
+    #[char('#')]
+    Obstacle,
+    #[char('^')]
+    Guard,
+}
+
+/// Parses the lab and returns it together with the starting position of the guard.
+pub fn parse(content: &str) -> Result<(Matrix<LabTile>, MatrixIdx)> {
+    let floor = Matrix::<LabTile>::try_from_str(content)?;
+    let start = floor
+        .find(&LabTile::Guard)
+        .ok_or(Error::malformed("no guard '^' on the map"))?;
+    Ok((floor, start))
+}
+
+pub fn part1(
+    (floor, start): &(Matrix<LabTile>, MatrixIdx),
+) -> (usize, Vec<(MatrixIdx, Direction)>) {
+    use LabTile::*;
+    let mut visited: BitGrid<DirectionSet> = BitGrid::like(floor);
+    let mut path = Vec::new();
+    let mut current = *start;
+    let mut dir = Direction::Up;
+    loop {
+        // first we update the state of the machine
+        match floor[current] {
+            Tile | Guard => {
+                visited.insert(&current, dir);
+            }
+            Obstacle => panic!("this should never happen"),
+        }
+
+        let next = loop {
+            let next = floor.offset_idx(&current, &dir.offset());
+            match next.map(|next| &floor[next]) {
+                Some(Obstacle) => dir = dir.right(),
+                _ => break next,
+            }
+        };
+        path.push((current, dir));
+        match next {
+            Some(next) => current = next,
+            None => break,
+        }
+    }
+    (visited.count_cells(), path)
+}
+
+/// Whether the guard walks in a loop from `start`, with an obstacle left of `start` in `dir`.
+/// `seen` is cleared and then holds the walk.
+fn path_has_loop(
+    floor: &Matrix<LabTile>,
+    start: MatrixIdx,
+    dir: Direction,
+    seen: &mut BitGrid<DirectionSet>,
+) -> bool {
+    use LabTile::*;
+    seen.clear();
+    let mut dir = dir;
+    let mut current = start;
+    let obstacle_location = start.checked_add(&dir.left().offset());
+    loop {
+        let next = loop {
+            let next = floor.offset_idx(&current, &dir.offset());
+            match next.map(|next| (next, &floor[next])) {
+                Some((next, _)) if Some(next) == obstacle_location => dir = dir.right(),
+                Some((_, Obstacle)) => dir = dir.right(),
+                _ => break next,
+            }
+        };
+        if !seen.insert(&current, dir) {
+            return true;
+        }
+        //println!("({:?}, {:?}), {:?}", current.row, current.col, dir);
+        match next {
+            Some(next) => current = next,
+            None => return false,
+        }
+    }
+}
+
+pub fn part2((floor, start): &(Matrix<LabTile>, MatrixIdx)) -> usize {
+    use LabTile::*;
+    let mut visited: BitGrid = BitGrid::like(floor);
+    // reused by every loop check
+    let mut seen = BitGrid::like(floor);
+    let mut dir = Direction::Up;
+    let mut current = *start;
+    let mut loop_obstructions: BitGrid = BitGrid::like(floor);
+    loop {
+        let next = loop {
+            let next = floor.offset_idx(&current, &dir.offset());
+            match next.map(|next| (next, &floor[next])) {
+                Some((_, Obstacle)) => dir = dir.right(),
+                Some((next, Tile)) => {
+                    // here we spawn a loop check with once to the right but only if this tile has
+                    // not been visited before
+                    // we can test for a loop by inserting a block as this would not alter the
+                    if !visited.contains(&next)
+                        && path_has_loop(floor, current, dir.right(), &mut seen)
+                    {
+                        loop_obstructions.insert(&next);
+                    }
+                    break Some(next);
+                }
+                _ => break next,
+            }
+        };
+        visited.insert(&current);
+        match next {
+            Some(next) => current = next,
+            None => break,
+        }
+    }
+    loop_obstructions.count()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST1: &str = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+    #[test]
+    fn check_loop() {
+        use Direction::*;
+        let loops = vec![
+            (6, 4, Up),
+            (6, 6, Left),
+            (7, 6, Down),
+            (8, 2, Up),
+            (8, 4, Up),
+            (8, 7, Left),
+        ];
+
+        let floor = Matrix::<LabTile>::try_from_str(TEST1).unwrap();
+        let mut seen = BitGrid::like(&floor);
+        for (row, col, dir) in loops {
+            let start = MatrixIdx::new(row, col);
+            assert!(path_has_loop(&floor, start, dir, &mut seen))
+        }
+    }
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(TEST1).unwrap()).0, 41);
+    }
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(TEST1).unwrap()), 6);
+    }
+}
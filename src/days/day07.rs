@@ -0,0 +1,133 @@
+use crate::error::Result;
+use crate::parser::{self, int, lines, list, pair, Located};
+use crate::solution::{Answer, Puzzle};
+
+pub struct Day07;
+
+impl Puzzle for Day07 {
+    const DAY: i32 = 7;
+    type Input = Vec<Equation>;
+
+    fn parse(content: &str) -> Result<Self::Input> {
+        parse(content)
+    }
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Equation {
+    lhs: usize,
+    rhs: Vec<usize>,
+}
+
+impl Equation {
+    fn check_solvable(&self) -> bool {
+        for num in 0..self.solution_count() {
+            if self.lhs == self.solution_number(num) {
+                return true;
+            }
+        }
+        false
+    }
+    /// Parses an equation like `3267: 81 40 27`.
+    fn parse(line: Located) -> Result<Self> {
+        let (lhs, rhs) = pair(": ", int, list(" ", int))(line)?;
+        Ok(Self { lhs, rhs })
+    }
+    fn solution_count(&self) -> u32 {
+        let base: u32 = 2;
+
+        base.pow((self.rhs.len() - 1) as u32)
+    }
+    fn solution_number(&self, num: u32) -> usize {
+        let mut sum = self.rhs[0];
+        for (idx, val) in self.rhs.iter().skip(1).enumerate() {
+            if ((num >> idx) & 1) == 1 {
+                //print!("+");
+                sum += val
+            } else {
+                //print!("*");
+                sum *= val
+            }
+        }
+
+        //println!("");
+        sum
+    }
+}
+pub fn parse(content: &str) -> Result<Vec<Equation>> {
+    parser::parse(content, lines(Equation::parse))
+}
+pub fn part1(equations: &[Equation]) -> usize {
+    let mut solution = 0;
+    for eq in equations {
+        let base = 3u32;
+        assert!(base.checked_pow((eq.rhs.len() - 1) as u32).is_some());
+        if eq.check_solvable() {
+            //println!("{:?} solved", eq);
+            solution += eq.lhs;
+        }
+    }
+    solution
+}
+
+fn is_separable(accumulate: usize, rhs: usize) -> Option<usize> {
+    let base = 10usize;
+    let module = base.pow(rhs.ilog10() + 1);
+    let num = accumulate - rhs;
+    let (lhs, ret) = (num / module, num.is_multiple_of(module));
+    //dbg!(&ret, &accumulate, &rhs, &lhs, &num, &module);
+    ret.then_some(lhs)
+}
+fn is_factor(accumulate: usize, rhs: usize) -> Option<usize> {
+    let (lhs, ret) = (accumulate / rhs, accumulate.is_multiple_of(rhs));
+    ret.then_some(lhs)
+}
+
+fn solve_recursive(accumulate: usize, rhss: &[usize]) -> bool {
+    let rhs = rhss[0];
+    if rhss.len() == 1 {
+        return rhs == accumulate;
+    }
+    if accumulate < rhs {
+        return false;
+    }
+    let rhss = &rhss[1..];
+    is_separable(accumulate, rhs).is_some_and(|lhs| solve_recursive(lhs, rhss))
+        || is_factor(accumulate, rhs).is_some_and(|lhs| solve_recursive(lhs, rhss))
+        || solve_recursive(accumulate - rhs, rhss)
+}
+
+pub fn part2(equations: &[Equation]) -> usize {
+    let mut solution = 0;
+    for eq in equations {
+        let mut rhss = eq.rhs.clone();
+        rhss.reverse();
+        if solve_recursive(eq.lhs, &rhss) {
+            solution += eq.lhs;
+        }
+    }
+    solution
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            Equation {
+                lhs: 10,
+                rhs: vec![1, 2, 3, 4, 5, 6]
+            },
+            Equation::parse(Located::new("10: 1 2 3 4 5 6")).unwrap()
+        );
+        assert!(parse("10: 1 2\n3267 81 40 27").is_err());
+    }
+}
@@ -0,0 +1,90 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    error::Result,
+    matrix::{FromChar, Grid, Matrix, MatrixIdx, MatrixIdxOffset},
+    point::Point2,
+    solution::{Answer, Puzzle},
+};
+
+pub struct Day08;
+
+impl Puzzle for Day08 {
+    const DAY: i32 = 8;
+    type Input = Matrix<Char>;
+
+    fn parse(content: &str) -> Result<Self::Input> {
+        parse(content)
+    }
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Hash, Eq, Copy)]
+pub struct Char(char);
+
+impl FromChar for Char {
+    fn try_from_char(char: &char) -> Option<Self> {
+        Some(Char(*char))
+    }
+}
+
+pub fn parse(content: &str) -> Result<Matrix<Char>> {
+    Matrix::try_from_str(content)
+}
+pub fn part1(grid: &Matrix<Char>) -> usize {
+    let mut antiodes = HashSet::new();
+    let mut antennas = HashMap::<Char, Vec<MatrixIdx>>::new();
+    for (idx, elem) in grid.idx_value_iter() {
+        if elem == &Char('.') {
+            continue;
+        }
+        let entry = antennas.entry(*elem).or_default();
+
+        for other in entry.iter() {
+            let (a, b) = (Point2::from(*other), Point2::from(idx));
+            let delta = a - b;
+            for antinode in [a + delta, b - delta] {
+                if let Some(antinode) = grid.point_idx(antinode) {
+                    antiodes.insert(antinode);
+                }
+            }
+        }
+        entry.push(idx);
+    }
+
+    antiodes.len()
+}
+pub fn part2(grid: &Matrix<Char>) -> usize {
+    let mut antiodes = HashSet::new();
+    let mut antennas = HashMap::<Char, Vec<MatrixIdx>>::new();
+    for (idx, elem) in grid.idx_value_iter() {
+        if elem == &Char('.') {
+            continue;
+        }
+        let entry = antennas.entry(*elem).or_default();
+
+        for other in entry.iter() {
+            // every grid point on the line through both antennas
+            let step = (Point2::from(*other) - Point2::from(idx)).reduced();
+            for step in [step, -step] {
+                let ray = grid.ray(idx, MatrixIdxOffset::from(step));
+                antiodes.extend(ray.map(|(antinode, _)| antinode));
+            }
+        }
+        entry.push(idx);
+    }
+
+    antiodes.len()
+}
+
+#[cfg(test)]
+mod test {
+
+    #[test]
+    fn test_parse() {}
+}
@@ -0,0 +1,195 @@
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Puzzle};
+pub struct Day09;
+
+impl Puzzle for Day09 {
+    const DAY: i32 = 9;
+    type Input = Vec<u32>;
+
+    fn parse(content: &str) -> Result<Self::Input> {
+        parse(content)
+    }
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+/// Parses the disk map, a single line of digits.
+pub fn parse(content: &str) -> Result<Vec<u32>> {
+    let content = content.trim_end();
+    if content.is_empty() {
+        return Err(Error::EmptyInput);
+    }
+    content
+        .chars()
+        .enumerate()
+        .map(|(column, char)| {
+            char.to_digit(10).ok_or(Error::UnexpectedChar {
+                char,
+                line: 1,
+                column: column + 1,
+            })
+        })
+        .collect()
+}
+
+pub fn part1(numbers: &[u32]) -> usize {
+    let mut disk = Vec::<Option<usize>>::new();
+    let mut id = 0;
+    let mut space = false;
+    for num in numbers {
+        let num = *num as usize;
+        if space {
+            let mut vec = vec![None; num];
+            disk.append(&mut vec);
+        } else {
+            let mut vec = vec![Some(id); num];
+            disk.append(&mut vec);
+            id += 1;
+        }
+        space = !space;
+    }
+    let mut idx = 0;
+    let mut rev_idx = disk.len();
+    let mut solution = 0;
+    loop {
+        let id = if let Some(id) = disk[idx] {
+            id
+        } else {
+            loop {
+                rev_idx -= 1;
+                if let Some(id) = disk[rev_idx] {
+                    break id;
+                }
+            }
+        };
+
+        if rev_idx <= idx {
+            break;
+        }
+        solution += idx * id;
+
+        idx += 1;
+    }
+
+    solution
+}
+
+#[derive(Clone, Copy, Debug)]
+enum DiskObjects {
+    Space(usize),
+    File(usize, usize),
+}
+
+// Noice lets try to write some pseudocode for this problem
+
+pub fn part2(numbers: &[u32]) -> usize {
+    let mut disk = Vec::<DiskObjects>::new();
+    let mut id = 0;
+    let mut space = false;
+    for num in numbers {
+        let num = *num as usize;
+        if space {
+            disk.push(DiskObjects::Space(num));
+        } else {
+            disk.push(DiskObjects::File(num, id));
+            id += 1;
+        }
+        space = !space;
+    }
+    disk.reverse();
+
+    let mut current_id = id - 1;
+    let mut idx = 0;
+    loop {
+        // first we find the idx of the diskobjects with current id
+
+        let disk_object = disk[idx];
+        //println!("looking for {:?} found {:?}", current_id, &disk_object);
+        let action = match disk_object {
+            DiskObjects::File(file_size, id) if (id == current_id) => {
+                // we look for the last space that could fit the file
+                let mut found = None;
+                for (i, object) in disk.iter().enumerate().skip(idx) {
+                    //println!(
+                    //    "index {:?}, checking {:?} current {:?}",
+                    //    idx, i, &current_id
+                    //);
+                    match object {
+                        DiskObjects::Space(space_size) if *space_size >= file_size => {
+                            found = Some((i, *space_size, file_size));
+                        }
+                        _ => (),
+                    }
+                }
+                found
+            }
+            _ => {
+                idx += 1;
+                continue;
+            }
+        };
+
+        if let Some((space_idx, space_size, file_size)) = action {
+            // here we need to perform the swapping operation
+            // typically all objects before and after index are spaces so we combine them if they
+            // are into one
+
+            if space_size == file_size {
+                //println!("spwapping {:?} with {:?}", &disk[idx], &disk[space_idx]);
+                disk.swap(idx, space_idx);
+            } else {
+                disk[space_idx] = DiskObjects::Space(file_size);
+                //println!("{:?}", &disk[space_idx]);
+                disk.insert(space_idx, DiskObjects::Space(space_size - file_size));
+                //println!("after insert {:?}", &disk[space_idx]);
+                //println!(
+                //"spwapping 2nd {:?} with {:?}",
+                //    &disk[idx],
+                //    &disk[space_idx + 1]
+                //);
+                disk.swap(idx, space_idx + 1);
+            }
+        }
+        //println!("{:?}", disk.clone().reverse());
+        if idx >= disk.len() || current_id == 0 {
+            break;
+        }
+        current_id -= 1;
+    }
+    disk.reverse();
+    //println!("{:?}", disk);
+
+    let mut solution = 0;
+    let mut idx = 0;
+    for elem in disk {
+        match elem {
+            DiskObjects::Space(size) => idx += size,
+            DiskObjects::File(space, id) => {
+                for _ in 0..space {
+                    //println!("{:?}, {:?}", idx, id);
+                    solution += idx * id;
+                    idx += 1;
+                }
+            }
+        }
+    }
+
+    solution
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parts() {
+        let numbers = parse("2333133121414131402\n").unwrap();
+        assert_eq!(part1(&numbers), 1928);
+        assert_eq!(part2(&numbers), 2858);
+        assert!(matches!(parse("\n"), Err(Error::EmptyInput)));
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{
+    error::Result,
+    matrix::{BitGrid, Grid, Matrix, MatrixIdx},
+    solution::{Answer, Puzzle},
+};
+
+pub struct Day10;
+
+impl Puzzle for Day10 {
+    const DAY: i32 = 10;
+    type Input = Matrix<u8>;
+
+    fn parse(content: &str) -> Result<Self::Input> {
+        parse(content)
+    }
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+/// The trail heads of height 9 reachable from `start` by climbing one step at a time.
+fn find_heads(start: &MatrixIdx, grid: &Matrix<u8>) -> Vec<MatrixIdx> {
+    let height = grid[start];
+    if height == 9 {
+        return vec![*start];
+    }
+    grid.neighbors4(start)
+        .filter(|(_, _, &next)| next == height + 1)
+        .flat_map(|(_, next_idx, _)| find_heads(&next_idx, grid))
+        .collect()
+}
+
+/// The number of distinct trails from `start` to a height of 9.
+fn find_trails(start: &MatrixIdx, grid: &Matrix<u8>) -> usize {
+    let height = grid[start];
+    if height == 9 {
+        return 1;
+    }
+    grid.neighbors4(start)
+        .filter(|(_, _, &next)| next == height + 1)
+        .map(|(_, next_idx, _)| find_trails(&next_idx, grid))
+        .sum()
+}
+pub fn parse(content: &str) -> Result<Matrix<u8>> {
+    Matrix::try_from_str_with(content, |c| c.to_digit(10).map(|c| c as u8))
+}
+pub fn part2(grid: &Matrix<u8>) -> usize {
+    let mut solution = 0;
+
+    let starts = grid.find_all(&0);
+    for start in starts {
+        solution += find_trails(&start, grid)
+    }
+    solution
+}
+pub fn part1(grid: &Matrix<u8>) -> usize {
+    let mut solution = 0;
+
+    let mut heads: BitGrid = BitGrid::like(grid);
+    let starts = grid.find_all(&0);
+    for start in starts {
+        heads.clear();
+        for head in find_heads(&start, grid) {
+            heads.insert(&head);
+        }
+        solution += heads.count();
+    }
+    solution
+}
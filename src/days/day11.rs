@@ -0,0 +1,156 @@
+use std::collections::HashMap;
+
+use crate::error::{Error, Result};
+use crate::params::{Param, Params};
+use crate::solution::{Answer, Puzzle};
+use crate::util::parse_number;
+
+pub struct Day11;
+
+impl Puzzle for Day11 {
+    const DAY: i32 = 11;
+    /// How often the stones blink in each part.
+    const PARAMS: &'static [Param] = &[("blinks1", "25"), ("blinks2", "75")];
+    /// The stones and the blinks of part 1 and part 2.
+    type Input = (Vec<usize>, usize, usize);
+
+    fn parse(content: &str) -> Result<Self::Input> {
+        Self::parse_with(content, &Params::new(Self::PARAMS))
+    }
+    fn parse_with(content: &str, params: &Params) -> Result<Self::Input> {
+        Ok((
+            parse(content)?,
+            params.get("blinks1")?,
+            params.get("blinks2")?,
+        ))
+    }
+    fn part1((stones, blinks, _): &Self::Input) -> Result<Answer> {
+        Ok(part1(stones, *blinks)?.into())
+    }
+    fn part2((stones, _, blinks): &Self::Input) -> Result<Answer> {
+        Ok(part2(stones, *blinks).into())
+    }
+}
+
+fn blink(stone: &str) -> Result<Vec<String>> {
+    Ok(match stone {
+        "" | "0" => vec!["1".to_string()],
+        "1" => vec!["2024".to_string()],
+        stone if stone.len() % 2 == 0 => {
+            let (a, b) = stone.split_at(stone.len() / 2);
+            vec![
+                a.to_string(),
+                match b.trim_start_matches("0") {
+                    "" => "0".to_owned(),
+                    num => num.to_owned(),
+                },
+            ]
+        }
+        stone => {
+            let grown = parse_number::<usize>(stone)?
+                .checked_mul(2024)
+                .ok_or_else(|| Error::malformed(format!("stone {stone} grows too large")))?;
+            vec![grown.to_string()]
+        }
+    })
+}
+fn do_step(stones: &[String]) -> Result<Vec<String>> {
+    let mut new_stones = Vec::new();
+    for stone in stones {
+        new_stones.extend(blink(stone)?);
+    }
+    Ok(new_stones)
+}
+
+/// Counts the stones a stone turns into, remembering counts already seen.
+#[derive(Default)]
+pub struct MemoizedStones {
+    mem: HashMap<(usize, usize), usize>,
+}
+
+fn has_even_digits(num: usize) -> bool {
+    let ndigits = num.ilog10() + 1;
+    ndigits.is_multiple_of(2)
+}
+fn split(num: usize) -> (usize, usize) {
+    let base = 10usize;
+    let div = base.pow(num.ilog10().div_ceil(2));
+
+    (num / div, num % div)
+}
+impl MemoizedStones {
+    pub fn new() -> Self {
+        Self {
+            mem: HashMap::new(),
+        }
+    }
+    pub fn count_stones(&mut self, stone: usize, steps: usize) -> usize {
+        //println!("{:?},{:?}", stone, steps);
+        if steps == 0 {
+            return 1;
+        }
+
+        if let Some(val) = self.mem.get(&(stone, steps)) {
+            return *val;
+        }
+
+        let ret = match stone {
+            0 => self.count_stones(1, steps - 1),
+            1 => self.count_stones(2024, steps - 1),
+            stone if has_even_digits(stone) => {
+                let (a, b) = split(stone);
+                self.count_stones(a, steps - 1) + self.count_stones(b, steps - 1)
+            }
+            stone => self.count_stones(stone * 2024, steps - 1),
+        };
+        self.mem.insert((stone, steps), ret);
+        ret
+    }
+}
+pub fn parse(content: &str) -> Result<Vec<usize>> {
+    content.split_whitespace().map(parse_number).collect()
+}
+pub fn part1(stones: &[usize], blinks: usize) -> Result<usize> {
+    let mut stones: Vec<String> = stones.iter().map(|stone| stone.to_string()).collect();
+
+    for _ in 0..blinks {
+        stones = do_step(&stones)?;
+    }
+
+    Ok(stones.len())
+}
+pub fn part2(stones: &[usize], blinks: usize) -> usize {
+    let mut memcnt = MemoizedStones::new();
+    let mut solution = 0;
+    for stone in stones {
+        solution += memcnt.count_stones(*stone, blinks);
+    }
+    solution
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_mem() {
+        let stones = [125, 17];
+
+        let mut mem = MemoizedStones::new();
+        let sol = mem.count_stones(stones[0], 25) + mem.count_stones(stones[1], 25);
+
+        assert_eq!(sol, 55312);
+    }
+    #[test]
+    fn test_part1() {
+        let stones = parse("125 17").unwrap();
+        assert_eq!(part1(&stones, 6).unwrap(), 22);
+        assert_eq!(part1(&stones, 25).unwrap(), 55312);
+        assert!(part1(&[10usize.pow(18)], 1).is_err());
+        assert_eq!(part2(&stones, 25), 55312);
+    }
+    #[test]
+    fn test_split() {
+        assert_eq!((100, 123), split(100123));
+        assert_eq!((1233, 3123), split(12333123));
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{
+    error::Result,
+    matrix::{Connectivity, Matrix, Region},
+    solution::{Answer, Puzzle},
+};
+
+pub struct Day12;
+
+impl Puzzle for Day12 {
+    const DAY: i32 = 12;
+    type Input = Matrix<char>;
+
+    fn parse(content: &str) -> Result<Self::Input> {
+        parse(content)
+    }
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+/// The plots of the garden, regions of the same kind of plant.
+fn find_plots(grid: &Matrix<char>) -> Vec<Region> {
+    grid.components(|a, b| a == b, Connectivity::Four).regions
+}
+
+pub fn parse(content: &str) -> Result<Matrix<char>> {
+    Matrix::try_from_str_with(content, |c| Some(*c))
+}
+pub fn part1(grid: &Matrix<char>) -> usize {
+    find_plots(grid)
+        .iter()
+        .map(|plot| plot.area * plot.perimeter)
+        .sum()
+}
+pub fn part2(grid: &Matrix<char>) -> usize {
+    find_plots(grid)
+        .iter()
+        .map(|plot| plot.area * plot.sides)
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::matrix::MatrixIdx;
+    #[test]
+    fn test_mem() {
+        let content = r#"AAAAAA
+AAABBA
+AAABBA
+ABBAAA
+ABBAAA
+AAAAAA"#;
+        let grid = Matrix::<char>::try_from_str_with(content, |c| Some(*c)).unwrap();
+        let plots = grid.components(|a, b| a == b, Connectivity::Four);
+
+        let label = plots.labels[MatrixIdx::new(0, 0)];
+        assert_eq!(12, plots.regions[label].sides);
+        // the two B plots touch diagonally, so they form a single hole
+        assert_eq!(1, plots.holes(label));
+        assert_eq!(368, part2(&grid));
+    }
+}
@@ -0,0 +1,140 @@
+use crate::error::Result;
+use crate::parser::FromRegex;
+use crate::point::Vec2;
+use crate::solution::{Answer, Puzzle};
+
+pub struct Day13;
+
+impl Puzzle for Day13 {
+    const DAY: i32 = 13;
+    type Input = Vec<Equation>;
+
+    fn parse(content: &str) -> Result<Self::Input> {
+        parse(content)
+    }
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+/// The moves of the buttons A and B and the position of the prize.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Equation {
+    a: Vec2,
+    b: Vec2,
+    c: Vec2,
+}
+
+/// One claw machine as written in the input.
+#[derive(FromRegex)]
+#[regex(
+    r"Button A: X\+(?<ax>\d+), Y\+(?<ay>\d+)\s+Button B: X\+(?<bx>\d+), Y\+(?<by>\d+)\s+Prize: X=(?<px>\d+), Y=(?<py>\d+)"
+)]
+struct Machine {
+    ax: i64,
+    ay: i64,
+    bx: i64,
+    by: i64,
+    px: i64,
+    py: i64,
+}
+impl From<Machine> for Equation {
+    fn from(m: Machine) -> Self {
+        Equation {
+            a: Vec2::new(m.ax, m.ay),
+            b: Vec2::new(m.bx, m.by),
+            c: Vec2::new(m.px, m.py),
+        }
+    }
+}
+
+pub fn parse(content: &str) -> Result<Vec<Equation>> {
+    Ok(Machine::parse_all(content)?
+        .into_iter()
+        .map(Equation::from)
+        .collect())
+}
+pub fn part1(equations: &[Equation]) -> i64 {
+    let mut solution = 0;
+    for eq in equations {
+        'outer: for b in 0..100 {
+            for a in 0..100 {
+                if eq.b * b + eq.a * a == eq.c {
+                    solution += a * 3 + b;
+                    break 'outer;
+                }
+            }
+        }
+    }
+    solution
+}
+pub fn gcd(mut n: i64, mut m: i64) -> i64 {
+    assert!(n != 0 && m != 0);
+    while m != 0 {
+        if m < n {
+            std::mem::swap(&mut m, &mut n);
+        }
+        m %= n;
+    }
+    n
+}
+const ADDIT: i64 = 10000000000000;
+pub fn part2(equations: &[Equation]) -> i64 {
+    let mut solution = 0;
+
+    for &(mut eq) in equations {
+        eq.c += Vec2::new(ADDIT, ADDIT);
+
+        // Cramer's rule, the buttons are independent unless they move in the same direction
+        let det = eq.a.cross(&eq.b);
+        if det == 0 {
+            continue;
+        }
+        let detx = eq.c.cross(&eq.b);
+        let dety = eq.a.cross(&eq.c);
+        // the prize is only won with a whole, non-negative number of presses of both buttons
+        if detx % det == 0 && dety % det == 0 {
+            let (a, b) = (detx / det, dety / det);
+            if a >= 0 && b >= 0 {
+                solution += a * 3 + b;
+            }
+        }
+    }
+
+    solution
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST1: &str = "Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+";
+
+    #[test]
+    fn test_part1() {
+        let machines = Machine::parse_all(TEST1).unwrap();
+        assert_eq!(machines.len(), 4);
+        assert_eq!((machines[1].bx, machines[1].py), (67, 12176));
+
+        let equations = parse(TEST1).unwrap();
+        assert_eq!(part1(&equations), 480);
+    }
+}
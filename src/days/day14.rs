@@ -0,0 +1,133 @@
+use std::cmp::Ordering::{Greater, Less};
+
+use crate::error::Result;
+use crate::matrix::{Grid, SparseGrid};
+use crate::params::{Param, Params};
+use crate::parser::FromRegex;
+use crate::point::{Point2, Vec2};
+use crate::solution::{Answer, Puzzle};
+
+pub struct Day14;
+
+impl Puzzle for Day14 {
+    const DAY: i32 = 14;
+    /// The size of the room, the example uses `rows=7 cols=11`.
+    const PARAMS: &'static [Param] = &[("rows", "103"), ("cols", "101")];
+    /// The robots and the number of rows and columns of the room.
+    type Input = (Robots, usize, usize);
+
+    fn parse(content: &str) -> Result<Self::Input> {
+        Self::parse_with(content, &Params::new(Self::PARAMS))
+    }
+    fn parse_with(content: &str, params: &Params) -> Result<Self::Input> {
+        Ok((parse(content)?, params.get("rows")?, params.get("cols")?))
+    }
+    fn part1((robots, nrows, ncols): &Self::Input) -> Result<Answer> {
+        Ok(part1(robots, *nrows, *ncols).into())
+    }
+    fn part2((robots, nrows, ncols): &Self::Input) -> Result<Answer> {
+        Ok(part2(robots, *nrows, *ncols).into())
+    }
+}
+
+/// Positions and speeds of all robots.
+pub type Robots = (Vec<Point2>, Vec<Vec2>);
+
+/// One robot as written in the input.
+#[derive(FromRegex)]
+#[regex(r"p=(?<x>\d+),(?<y>\d+) v=(?<dx>-?\d+),(?<dy>-?\d+)")]
+struct Robot {
+    x: i64,
+    y: i64,
+    dx: i64,
+    dy: i64,
+}
+
+pub fn parse(content: &str) -> Result<Robots> {
+    Ok(Robot::parse_all(content)?
+        .into_iter()
+        .map(|r| (Point2::new(r.x, r.y), Vec2::new(r.dx, r.dy)))
+        .unzip())
+}
+
+fn step(poss: &mut [Point2], speeds: &[Vec2], nrows: usize, ncols: usize) {
+    for (pos, speed) in poss.iter_mut().zip(speeds) {
+        // the room wraps around at its edges
+        *pos = (*pos + *speed).wrapped_in((nrows, ncols));
+    }
+}
+fn safety_score(positions: &[Point2], nrows: usize, ncols: usize) -> i64 {
+    let (mid_y, mid_x) = (nrows as i64 / 2, ncols as i64 / 2);
+    let mut q1 = 0;
+    let mut q2 = 0;
+    let mut q3 = 0;
+    let mut q4 = 0;
+    for pos in positions {
+        match (pos.x.cmp(&mid_x), pos.y.cmp(&mid_y)) {
+            (Greater, Greater) => q4 += 1,
+            (Less, Greater) => q3 += 1,
+            (Greater, Less) => q2 += 1,
+            (Less, Less) => q1 += 1,
+            _ => (),
+        }
+    }
+
+    q1 * q2 * q3 * q4
+}
+
+pub fn part1((positions, speeds): &Robots, nrows: usize, ncols: usize) -> i64 {
+    let mut positions = positions.clone();
+    for _ in 0..100 {
+        step(&mut positions, speeds, nrows, ncols);
+    }
+
+    safety_score(&positions, nrows, ncols)
+}
+/// The cells holding at least one robot.
+fn occupied(positions: &[Point2]) -> SparseGrid<char> {
+    positions.iter().map(|&pos| (pos, 'x')).collect()
+}
+
+fn neighbour_score(robots: &SparseGrid<char>) -> usize {
+    robots
+        .iter()
+        .map(|(pos, _)| robots.neighbors4(&pos).count())
+        .sum()
+}
+/// Returns the first step showing the christmas tree, which is the frame where the most robots
+/// stand next to each other. Robot positions repeat after `nrows * ncols` steps.
+pub fn part2((positions, speeds): &Robots, nrows: usize, ncols: usize) -> i64 {
+    let mut positions = positions.clone();
+    let mut best = (0, 0);
+    for count in 0..nrows * ncols {
+        let score = neighbour_score(&occupied(&positions));
+        if score > best.0 {
+            best = (score, count);
+        }
+        step(&mut positions, speeds, nrows, ncols);
+    }
+    best.1 as i64
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST1: &str = "p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3";
+
+    #[test]
+    fn test_1() {
+        assert_eq!(part1(&parse(TEST1).unwrap(), 7, 11), 12);
+    }
+}
@@ -0,0 +1,254 @@
+use std::collections::HashSet;
+
+use crate::{
+    error::{Error, Result},
+    matrix::{Direction, FromChar, Matrix, MatrixElement, MatrixIdx, ToChar},
+    solution::{Answer, Puzzle},
+};
+
+pub struct Day15;
+
+impl Puzzle for Day15 {
+    const DAY: i32 = 15;
+    type Input = (Matrix<Tiles>, Vec<Direction>);
+
+    fn parse(content: &str) -> Result<Self::Input> {
+        parse(content)
+    }
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        part1(input).map(Answer::from)
+    }
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        part2(input).map(Answer::from)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ToChar)]
+enum Tiles2 {
+    #[char('#')]
+    Wall,
+    #[char('.')]
+    Free,
+    #[char('[')]
+    BoxLeft,
+    #[char(']')]
+    BoxRight,
+    #[char('@')]
+    Robot,
+}
+#[derive(Debug, Clone, Copy, PartialEq, FromChar, ToChar)]
+pub enum Tiles {
+    #[char('#')]
+    Wall,
+    #[char('.')]
+    Free,
+    #[char('O')]
+    Box,
+    #[char('@')]
+    Robot,
+}
+
+/// Parses the robot moves, `first_line` is the line number of `content` within the whole input.
+fn parse_dir(content: &str, first_line: usize) -> Result<Vec<Direction>> {
+    let mut directions = Vec::new();
+    for (row, line) in content.lines().enumerate() {
+        for (column, c) in line.chars().enumerate() {
+            directions.push(match c {
+                '<' => Direction::Left,
+                '>' => Direction::Right,
+                '^' => Direction::Up,
+                'v' => Direction::Down,
+                _ => {
+                    return Err(Error::UnexpectedChar {
+                        char: c,
+                        line: first_line + row,
+                        column: column + 1,
+                    })
+                }
+            });
+        }
+    }
+    Ok(directions)
+}
+
+fn expand(grid: &Matrix<Tiles>) -> Matrix<Tiles2> {
+    let data = vec![Tiles2::Robot; grid.width() * grid.height() * 2];
+    let mut grid2 = Matrix::<Tiles2>::from(data, grid.width() * 2);
+    for (idx, tile) in grid.idx_value_iter() {
+        let lidx = MatrixIdx::new(idx.row, idx.col * 2);
+        let ridx = MatrixIdx::new(idx.row, idx.col * 2 + 1);
+        use Tiles::*;
+        (grid2[lidx], grid2[ridx]) = match tile {
+            Free => (Tiles2::Free, Tiles2::Free),
+            Box => (Tiles2::BoxLeft, Tiles2::BoxRight),
+            Wall => (Tiles2::Wall, Tiles2::Wall),
+            Robot => (Tiles2::Robot, Tiles2::Free),
+        };
+    }
+    grid2
+}
+/// Moves the tile at `idx` and the boxes in front of it, returns where the tile ended up or
+/// `None` if it is blocked by a wall or the edge of the map.
+fn domove(grid: &mut Matrix<Tiles>, idx: MatrixIdx, direction: Direction) -> Option<MatrixIdx> {
+    use Tiles::*;
+    let start = grid[&idx];
+    let target_idx = grid.offset_idx(&idx, &direction.offset())?;
+
+    match grid[target_idx] {
+        Free => (),
+        Box => {
+            domove(grid, target_idx, direction)?;
+        }
+        // parse makes sure the moving robot is the only one
+        Wall | Robot => return None,
+    }
+    grid[idx] = Free;
+    grid[target_idx] = start;
+    Some(target_idx)
+}
+
+pub fn parse(content: &str) -> Result<(Matrix<Tiles>, Vec<Direction>)> {
+    let (a, b) = content.split_once("\n\n").ok_or(Error::malformed(
+        "expected map and moves separated by an empty line",
+    ))?;
+    let grid = parse_grid(a)?;
+    if grid.find_all(&Tiles::Robot).len() != 1 {
+        return Err(Error::malformed(
+            "expected exactly one robot '@' on the map",
+        ));
+    }
+    // the moves start after the map and the separating empty line
+    Ok((grid, parse_dir(b, a.lines().count() + 2)?))
+}
+fn parse_grid(content: &str) -> Result<Matrix<Tiles>> {
+    Matrix::<Tiles>::try_from_str(content)
+}
+fn find_robot<T: MatrixElement>(grid: &Matrix<T>, robot: &T) -> Result<MatrixIdx> {
+    grid.find(robot)
+        .ok_or(Error::malformed("no robot '@' on the map"))
+}
+pub fn part1((grid, directions): &(Matrix<Tiles>, Vec<Direction>)) -> Result<usize> {
+    let mut solution = 0;
+
+    let mut grid = grid.clone();
+    let mut robot = find_robot(&grid, &Tiles::Robot)?;
+    for &dir in directions {
+        if let Some(next) = domove(&mut grid, robot, dir) {
+            robot = next
+        }
+    }
+
+    for b in grid.find_all(&Tiles::Box) {
+        solution += b.row * 100 + b.col
+    }
+    Ok(solution)
+}
+
+/// The moves `(from, to)` of the cells pushed by the tile at `idx`, starting with the tile
+/// itself, `None` if any of them is blocked by a wall or the edge of the map.
+fn can_move(
+    grid: &Matrix<Tiles2>,
+    idx: MatrixIdx,
+    direction: Direction,
+) -> Option<Vec<(MatrixIdx, MatrixIdx)>> {
+    use Direction::*;
+    use Tiles2::*;
+    let dir = direction.offset();
+    let left = Left.offset();
+    let right = Right.offset();
+
+    // the cells to move in the order they are queued, every cell once
+    let mut tomove = vec![idx];
+    let mut queued = HashSet::from([idx]);
+    let mut moves = Vec::new();
+
+    while let Some(&idx) = tomove.get(moves.len()) {
+        let next = grid.offset_idx(&idx, &dir)?;
+        moves.push((idx, next));
+        let tile = &grid[next];
+        match (tile, direction) {
+            (Free, _) => (),
+            (BoxRight | BoxLeft, Left | Right) => tomove.push(next),
+            (BoxLeft | BoxRight, Up | Down) => {
+                let off = if tile == &BoxLeft { right } else { left };
+                for half in [grid.offset_idx(&next, &off)?, next] {
+                    if queued.insert(half) {
+                        tomove.push(half);
+                    }
+                }
+            }
+            // parse makes sure the moving robot is the only one
+            (Wall | Robot, _) => return None,
+        }
+    }
+    Some(moves)
+}
+fn perform_move(grid: &mut Matrix<Tiles2>, moves: &[(MatrixIdx, MatrixIdx)]) {
+    let vals: Vec<_> = moves.iter().map(|&(from, to)| (to, grid[from])).collect();
+    for &(from, _) in moves {
+        grid[from] = Tiles2::Free;
+    }
+    for (idx, val) in vals {
+        grid[idx] = val;
+    }
+}
+
+pub fn part2((grid, directions): &(Matrix<Tiles>, Vec<Direction>)) -> Result<usize> {
+    let mut solution = 0;
+    let mut grid2 = expand(grid);
+    let mut robot = find_robot(&grid2, &Tiles2::Robot)?;
+    for &dir in directions {
+        if let Some(moves) = can_move(&grid2, robot, dir) {
+            perform_move(&mut grid2, &moves);
+            robot = moves[0].1
+        }
+    }
+
+    for b in grid2.find_all(&Tiles2::BoxLeft) {
+        solution += b.row * 100 + b.col
+    }
+    Ok(solution)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST1: &str = "########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<";
+
+    #[test]
+    fn test_1() {
+        assert_eq!(part1(&parse(TEST1).unwrap()).unwrap(), 2028);
+    }
+    #[test]
+    fn test_parse_dir_error() {
+        let content = TEST1.replace("<^^>", "<^x>");
+        match parse(&content) {
+            Err(Error::UnexpectedChar { char, line, column }) => {
+                assert_eq!((char, line, column), ('x', 10, 3));
+            }
+            other => panic!("expected unexpected char error, got {other:?}"),
+        }
+        assert!(parse(&TEST1.replace("#.#.O", "#.#@O")).is_err());
+        assert!(parse(&TEST1.replace("##@.O", "##..O")).is_err());
+    }
+    #[test]
+    fn test_edge_of_map() {
+        // without surrounding walls the edge of the map blocks like a wall
+        let input = parse("@.O.\n....\n\n<^>>>>v").unwrap();
+        assert_eq!(part1(&input).unwrap(), 3);
+        assert_eq!(part2(&input).unwrap(), 5);
+        let input = parse("..\n@O\n\nv>^>").unwrap();
+        assert_eq!(part1(&input).unwrap(), 101);
+        assert_eq!(part2(&input).unwrap(), 102);
+    }
+}
@@ -0,0 +1,174 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::{
+    error::{Error, Result},
+    matrix::{search, Direction, FromChar, Grid, Matrix, MatrixIdx, ToChar},
+    solution::{Answer, Puzzle},
+};
+
+pub struct Day16;
+
+impl Puzzle for Day16 {
+    const DAY: i32 = 16;
+    type Input = (Matrix<Tiles>, MatrixIdx, MatrixIdx);
+
+    fn parse(content: &str) -> Result<Self::Input> {
+        parse(content)
+    }
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        part1(input).map(Answer::from)
+    }
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        part2(input).map(Answer::from)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, FromChar, ToChar)]
+pub enum Tiles {
+    #[char('#')]
+    Wall,
+    #[char('.')]
+    Free,
+    #[char('S')]
+    Start,
+    #[char('E')]
+    End,
+}
+
+enum Input {
+    TurnLeft,
+    TurnRight,
+    Step,
+}
+const ALL_INPUTS: [Input; 3] = [Input::TurnLeft, Input::TurnRight, Input::Step];
+#[derive(PartialEq, Hash, Eq, Clone, Copy, Debug)]
+pub struct State(pub MatrixIdx, pub Direction);
+fn backward(state: &State, input: &Input) -> (State, usize) {
+    let State(pos, dir) = state;
+    match input {
+        Input::TurnLeft => (State(*pos, dir.right()), 1000),
+        Input::TurnRight => (State(*pos, dir.left()), 1000),
+        Input::Step => (State(pos - dir.offset(), *dir), 1),
+    }
+}
+fn forward(state: &State, input: &Input) -> (State, usize) {
+    let State(pos, dir) = state;
+    match input {
+        Input::TurnLeft => (State(*pos, dir.left()), 1000),
+        Input::TurnRight => (State(*pos, dir.right()), 1000),
+        Input::Step => (State(pos + dir.offset(), *dir), 1),
+    }
+}
+/// Lowest cost from every reachable state to the end tile, found by searching backwards.
+pub fn compute_costmap(grid: &Matrix<Tiles>, end: MatrixIdx) -> HashMap<State, usize> {
+    // the possible end states arrive at the end tile from one of its free neighbours
+    let ends = grid
+        .neighbors4(&end)
+        .filter(|(_, _, tile)| **tile == Tiles::Free)
+        .map(|(dir, _, _)| State(end, dir.opposite()));
+    let previous = |state: &State| {
+        ALL_INPUTS
+            .iter()
+            .map(|input| backward(state, input))
+            .filter(|(prev, _)| matches!(grid.get(&prev.0), Some(Tiles::Free | Tiles::Start)))
+            .collect::<Vec<_>>()
+    };
+    search::dijkstra_by(ends, previous, |_| false).distances
+}
+/// Parses the maze and returns it together with the start and end tile.
+pub fn parse(content: &str) -> Result<(Matrix<Tiles>, MatrixIdx, MatrixIdx)> {
+    let grid = Matrix::<Tiles>::try_from_str(content)?;
+    let start = grid
+        .find(&Tiles::Start)
+        .ok_or(Error::malformed("no start tile 'S' in the maze"))?;
+    let end = grid
+        .find(&Tiles::End)
+        .ok_or(Error::malformed("no end tile 'E' in the maze"))?;
+    Ok((grid, start, end))
+}
+pub fn lowest_cost(cost_map: &HashMap<State, usize>, start: &State) -> Result<usize> {
+    cost_map.get(start).copied().ok_or(Error::malformed(
+        "the end tile can not be reached from the start",
+    ))
+}
+pub fn part1((grid, start, end): &(Matrix<Tiles>, MatrixIdx, MatrixIdx)) -> Result<usize> {
+    let start = State(*start, Direction::Right);
+    let cost_map = compute_costmap(grid, *end);
+    lowest_cost(&cost_map, &start)
+}
+
+pub fn part2((grid, start, end): &(Matrix<Tiles>, MatrixIdx, MatrixIdx)) -> Result<usize> {
+    let start = State(*start, Direction::Right);
+    let costmap = compute_costmap(grid, *end);
+    let mut front = VecDeque::from([(start, lowest_cost(&costmap, &start)?)]);
+    let mut visited = HashSet::new();
+    while let Some((state, cost_to_go)) = front.pop_front() {
+        for input in ALL_INPUTS {
+            let (nstate, cost) = forward(&state, &input);
+            if let Some(remaining) = costmap.get(&nstate) {
+                // here we have optimal route found
+                if cost_to_go.checked_sub(cost) == Some(*remaining) {
+                    front.push_back((nstate, *remaining));
+                }
+            }
+        }
+        visited.insert(state.0);
+    }
+    Ok(visited.len())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse, part1, part2};
+
+    const TEST1: &str = "###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############";
+
+    const TEST2: &str = "#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################";
+
+    #[test]
+    fn test_part1_1() {
+        assert_eq!(part1(&parse(TEST1).unwrap()).unwrap(), 7036);
+    }
+    #[test]
+    fn test_part1_2() {
+        assert_eq!(part1(&parse(TEST2).unwrap()).unwrap(), 11048);
+    }
+    #[test]
+    fn test_part2_1() {
+        assert_eq!(part2(&parse(TEST1).unwrap()).unwrap(), 45);
+    }
+    #[test]
+    fn test_part2_2() {
+        assert_eq!(part2(&parse(TEST2).unwrap()).unwrap(), 64);
+    }
+}
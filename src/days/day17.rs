@@ -0,0 +1,194 @@
+use crate::error::{Error, Result};
+use crate::parser::FromRegex;
+use crate::solution::{Answer, Puzzle};
+use crate::util::parse_number;
+
+pub struct Day17;
+
+impl Puzzle for Day17 {
+    const DAY: i32 = 17;
+    type Input = (Cpu, Vec<u8>);
+
+    fn parse(content: &str) -> Result<Self::Input> {
+        parse(content)
+    }
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input)?.into())
+    }
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input)?.into())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Cpu {
+    pub a: i64,
+    pub b: i64,
+    pub c: i64,
+    pub ip: usize,
+}
+impl Cpu {
+    pub fn new(a: i64, b: i64, c: i64, ip: usize) -> Self {
+        Self { a, b, c, ip }
+    }
+    fn combo(&self, operand: u8) -> Result<i64> {
+        match operand {
+            0..=3 => Ok(operand as i64),
+            4 => Ok(self.a),
+            5 => Ok(self.b),
+            6 => Ok(self.c),
+            _ => Err(Error::malformed(format!("invalid combo operand {operand}"))),
+        }
+    }
+    /// `a` divided by two to the power of the combo `operand`.
+    fn divide(&self, operand: u8) -> Result<i64> {
+        // the registers never become negative, so shifting by 63 and more gives 0
+        Ok(self.a >> self.combo(operand)?.min(63))
+    }
+    /// Runs the program until the instruction pointer leaves it and returns its output.
+    ///
+    /// Fails on an unknown opcode or the reserved combo operand 7.
+    pub fn operate(&mut self, instructions: &[u8]) -> Result<Vec<i64>> {
+        let mut out = Vec::new();
+        while let (Some(&operator), Some(&operand)) =
+            (instructions.get(self.ip), instructions.get(self.ip + 1))
+        {
+            self.ip += 2;
+            match operator {
+                0 => self.a = self.divide(operand)?,
+                1 => self.b ^= operand as i64,
+                2 => self.b = self.combo(operand)? % 8,
+                3 => {
+                    if self.a != 0 {
+                        self.ip = operand as usize
+                    }
+                }
+                4 => self.b ^= self.c,
+                5 => {
+                    out.push(self.combo(operand)? % 8);
+                }
+                6 => self.b = self.divide(operand)?,
+                7 => self.c = self.divide(operand)?,
+                _ => return Err(Error::malformed(format!("invalid opcode {operator}"))),
+            }
+        }
+        Ok(out)
+    }
+}
+/// The initial values of the registers.
+#[derive(FromRegex)]
+#[regex(r"Register A: (?<a>\d+)\s+Register B: (?<b>\d+)\s+Register C: (?<c>\d+)")]
+struct Registers {
+    a: i64,
+    b: i64,
+    c: i64,
+}
+
+pub fn parse(content: &str) -> Result<(Cpu, Vec<u8>)> {
+    let (registers, program) = content.split_once("\n\n").ok_or(Error::malformed(
+        "expected registers and program separated by an empty line",
+    ))?;
+    let Registers { a, b, c } = Registers::parse_one(registers)?;
+    let cpu = Cpu::new(a, b, c, 0);
+
+    let (_, instructions) = program.split_once(": ").ok_or(Error::malformed(
+        "expected 'Program: ' followed by the instructions",
+    ))?;
+    let instructions: Vec<u8> = instructions
+        .split(",")
+        .map(|s| parse_number(s.trim()))
+        .collect::<Result<_>>()?;
+    if let Some(value) = instructions.iter().find(|&&value| value > 7) {
+        return Err(Error::malformed(format!(
+            "{value} is not a 3-bit opcode or operand"
+        )));
+    }
+    if instructions.len() < 2 || !instructions.len().is_multiple_of(2) {
+        return Err(Error::malformed(
+            "expected the program to be pairs of opcode and operand",
+        ));
+    }
+    Ok((cpu, instructions))
+}
+pub fn part1((cpu, instructions): &(Cpu, Vec<u8>)) -> Result<String> {
+    let mut cpu = cpu.clone();
+    let out: Vec<String> = cpu
+        .operate(instructions)?
+        .iter()
+        .map(i64::to_string)
+        .collect();
+    Ok(out.join(","))
+}
+pub fn part2((cpu, instructions): &(Cpu, Vec<u8>)) -> Result<i64> {
+    let mut cpu = cpu.clone();
+    let mut a = 0;
+    for i in instructions.iter().rev() {
+        a <<= 3;
+        for j in 0..1024 {
+            cpu.a = a + j;
+            cpu.b = 0;
+            cpu.ip = 0;
+            cpu.c = 0;
+            let out = cpu.operate(&instructions[0..instructions.len() - 2])?;
+            if out.len() == 1 && out[0] == *i as i64 {
+                a += j;
+                break;
+            }
+        }
+    }
+    Ok(a)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST1: &str = "Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0";
+
+    #[test]
+    fn test_part1_1() {
+        assert_eq!(
+            part1(&parse(TEST1).unwrap()).unwrap(),
+            "4,6,3,5,6,3,5,2,1,0"
+        );
+    }
+    #[test]
+    fn test_invalid_program() {
+        let program = |program: &str| parse(&TEST1.replace("0,1,5,4,3,0", program));
+        assert!(program("0,1,5").is_err());
+        assert!(program("8,1").is_err());
+        assert!(part1(&program("5,7").unwrap()).is_err());
+        assert_eq!(part1(&program("1,7,5,5").unwrap()).unwrap(), "7");
+    }
+    #[test]
+    fn test_part1_2() {
+        let mut cpu = Cpu::new(0, 0, 9, 0);
+        cpu.operate(&[2, 6]).unwrap();
+        assert_eq!(cpu.b, 1);
+
+        let mut cpu = Cpu::new(10, 0, 0, 0);
+        let out = cpu.operate(&[5, 0, 5, 1, 5, 4]).unwrap();
+        assert_eq!(out, vec![0, 1, 2]);
+
+        let mut cpu = Cpu::new(2024, 0, 0, 0);
+        let out = cpu.operate(&[0, 1, 5, 4, 3, 0]).unwrap();
+        assert_eq!(out, vec![4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0]);
+        assert_eq!(cpu.a, 0);
+
+        let mut cpu = Cpu::new(0, 29, 0, 0);
+        cpu.operate(&[1, 7]).unwrap();
+        assert_eq!(cpu.b, 26);
+
+        let mut cpu = Cpu::new(0, 2024, 43690, 0);
+        cpu.operate(&[4, 0]).unwrap();
+        assert_eq!(cpu.b, 44354);
+    }
+    #[test]
+    fn test_part2_1() {}
+    #[test]
+    fn test_part2_2() {}
+}
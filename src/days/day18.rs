@@ -0,0 +1,133 @@
+use crate::{
+    error::{Error, Result},
+    matrix::{search, SparseGrid},
+    params::{Param, Params},
+    point::Point2,
+    solution::{Answer, Puzzle},
+    util::parse_number,
+};
+
+pub struct Day18;
+
+impl Puzzle for Day18 {
+    const DAY: i32 = 18;
+    /// Width and height of the memory space and the bytes fallen in part 1, the example uses
+    /// `size=7 fallen=12`.
+    const PARAMS: &'static [Param] = &[("size", "71"), ("fallen", "1024")];
+    /// The falling bytes, the size of the memory space and the bytes fallen in part 1.
+    type Input = (Vec<Point2>, usize, usize);
+
+    fn parse(content: &str) -> Result<Self::Input> {
+        Self::parse_with(content, &Params::new(Self::PARAMS))
+    }
+    fn parse_with(content: &str, params: &Params) -> Result<Self::Input> {
+        Ok((parse(content)?, params.get("size")?, params.get("fallen")?))
+    }
+    fn part1((bytes, size, fallen): &Self::Input) -> Result<Answer> {
+        part1(bytes, *size, *fallen).map(Answer::from)
+    }
+    fn part2((bytes, size, _): &Self::Input) -> Result<Answer> {
+        part2(bytes, *size)
+    }
+}
+
+pub fn parse(content: &str) -> Result<Vec<Point2>> {
+    let mut bytes = Vec::new();
+    for line in content.lines() {
+        let (a, b) = line
+            .split_once(",")
+            .ok_or_else(|| Error::malformed(format!("expected a position 'x,y' in '{line}'")))?;
+        bytes.push(Point2::new(parse_number(a)?, parse_number(b)?));
+    }
+    Ok(bytes)
+}
+/// The first `fallen` bytes, which corrupt the few cells they hit of the memory space.
+fn corrupted_memory(bytes: &[Point2], fallen: usize) -> SparseGrid<()> {
+    bytes.iter().take(fallen).map(|byte| (*byte, ())).collect()
+}
+/// Searches the free cells from the top left corner to the exit in the bottom right one.
+fn find_exit(corrupted: &SparseGrid<()>, size: usize) -> search::Search<Point2> {
+    let exit = Point2::new(size as i64 - 1, size as i64 - 1);
+    let memory = (Point2::new(0, 0), exit);
+    search::bfs_by(
+        [Point2::new(0, 0)],
+        |cell| corrupted.passable_neighbors(cell, memory, |byte| byte.is_none()),
+        |cell| *cell == exit,
+    )
+}
+pub fn part1(bytes: &[Point2], size: usize, fallen: usize) -> Result<usize> {
+    let corrupted = corrupted_memory(bytes, fallen);
+    let found = find_exit(&corrupted, size);
+    found
+        .goal
+        .and_then(|exit| found.distance(&exit))
+        .ok_or(Error::malformed("the exit can not be reached"))
+}
+/// The first byte cutting off the exit, as `x,y`.
+///
+/// More bytes never open the exit again, so the number of fallen bytes is bisected.
+pub fn part2(all_corrupted: &[Point2], size: usize) -> Result<Answer> {
+    let cut_off = |fallen| {
+        find_exit(&corrupted_memory(all_corrupted, fallen), size)
+            .goal
+            .is_none()
+    };
+    let (mut reachable, mut blocked) = (0, all_corrupted.len());
+    if !cut_off(blocked) {
+        return Err(Error::malformed("the exit is never cut off"));
+    }
+    while blocked - reachable > 1 {
+        let fallen = (reachable + blocked) / 2;
+        if cut_off(fallen) {
+            blocked = fallen;
+        } else {
+            reachable = fallen;
+        }
+    }
+    let byte = all_corrupted[blocked - 1];
+    Ok(Answer::Coord(byte.x, byte.y))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST1: &str = "5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(TEST1).unwrap(), 7, 12).unwrap(), 22);
+        assert!(parse(&TEST1.replace("2,1", "2 1")).is_err());
+    }
+    #[test]
+    fn test_part2() {
+        assert_eq!(
+            part2(&parse(TEST1).unwrap(), 7).unwrap(),
+            Answer::Coord(6, 1)
+        );
+    }
+}
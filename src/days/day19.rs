@@ -0,0 +1,127 @@
+use crate::error::{Error, Result};
+use crate::matrix::{FromChar, ToChar};
+use crate::solution::{Answer, Puzzle};
+
+pub struct Day19;
+
+impl Puzzle for Day19 {
+    const DAY: i32 = 19;
+    type Input = (Vec<Towel>, Vec<Design>);
+
+    fn parse(content: &str) -> Result<Self::Input> {
+        parse(content)
+    }
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+#[derive(Debug, PartialEq, FromChar, ToChar)]
+pub enum Color {
+    #[char('w')]
+    White,
+    #[char('u')]
+    Blue,
+    #[char('b')]
+    Black,
+    #[char('r')]
+    Red,
+    #[char('g')]
+    Green,
+}
+pub type Towel = Vec<Color>;
+pub type Design = Vec<Color>;
+/// Parses the stripes of a single towel or design found at `line` and `column` of the input.
+fn parse_colors(stripes: &str, line: usize, column: usize) -> Result<Vec<Color>> {
+    stripes
+        .chars()
+        .enumerate()
+        .map(|(idx, c)| {
+            Color::try_from_char(&c).ok_or(Error::UnexpectedChar {
+                char: c,
+                line,
+                column: column + idx,
+            })
+        })
+        .collect()
+}
+pub fn parse(content: &str) -> Result<(Vec<Towel>, Vec<Design>)> {
+    let (top, bot) = content.split_once("\n\n").ok_or(Error::malformed(
+        "expected towels and designs separated by an empty line",
+    ))?;
+    let mut towels = Vec::new();
+    let mut column = 1;
+    for towel_str in top.trim_end().split(", ") {
+        towels.push(parse_colors(towel_str, 1, column)?);
+        column += towel_str.len() + ", ".len();
+    }
+    // designs start after the towels and the separating empty line
+    let first_line = top.lines().count() + 2;
+    let designs = bot
+        .lines()
+        .enumerate()
+        .map(|(row, line)| parse_colors(line, first_line + row, 1))
+        .collect::<Result<_>>()?;
+    Ok((towels, designs))
+}
+fn is_possible(design: &[Color], towels: &[Vec<Color>]) -> bool {
+    if design.is_empty() {
+        return true;
+    }
+    for towel in towels {
+        if design.starts_with(towel) && is_possible(&design[towel.len()..], towels) {
+            return true;
+        }
+    }
+    false
+}
+pub fn part1((towels, designs): &(Vec<Towel>, Vec<Design>)) -> i64 {
+    let mut solution = 0;
+    for design in designs {
+        if is_possible(design, towels) {
+            solution += 1;
+        }
+    }
+    solution
+}
+pub fn part2(_input: &(Vec<Towel>, Vec<Design>)) -> i64 {
+    0
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    #[test]
+    fn test_1() {
+        let content = "r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb";
+        assert_eq!(part1(&parse(content).unwrap()), 6)
+    }
+    #[test]
+    fn test_parse_towel_error() {
+        match parse(" r, wx\n\nrw") {
+            Err(Error::UnexpectedChar { char, line, column }) => {
+                assert_eq!((char, line, column), (' ', 1, 1));
+            }
+            other => panic!("expected unexpected char error, got {other:?}"),
+        }
+        match parse("r, wx\n\nrw") {
+            Err(Error::UnexpectedChar { char, line, column }) => {
+                assert_eq!((char, line, column), ('x', 1, 5));
+            }
+            other => panic!("expected unexpected char error, got {other:?}"),
+        }
+    }
+}
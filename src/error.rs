@@ -0,0 +1,134 @@
+use std::fmt::{self, Display};
+use std::num::ParseIntError;
+use std::path::PathBuf;
+
+/// Errors raised while loading or parsing puzzle inputs and the files kept next to them.
+///
+/// Line, row and column numbers are 1-based, matching what an editor shows for the input file.
+#[derive(Debug)]
+pub enum Error {
+    /// The input file could not be read.
+    MissingInput {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    /// A file such as the benchmark baselines could not be written.
+    WriteFailed {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    /// The input contains nothing to parse.
+    EmptyInput,
+    /// A grid row does not have the same width as the first row.
+    RaggedGrid {
+        row: usize,
+        expected: usize,
+        actual: usize,
+    },
+    /// A character that is not allowed at this position of the input.
+    UnexpectedChar {
+        char: char,
+        line: usize,
+        column: usize,
+    },
+    /// A token that should be a number but could not be parsed as one.
+    InvalidNumber {
+        value: String,
+        source: ParseIntError,
+    },
+    /// The input does not have the structure the puzzle describes, e.g. a missing section.
+    Malformed(String),
+    /// The input does not match the format described with [`crate::parser`].
+    Syntax {
+        line: usize,
+        column: usize,
+        expected: String,
+        found: String,
+    },
+    /// A record of the input, see [`crate::parser::FromRegex`], is invalid or does not match.
+    InvalidRecord {
+        record: usize,
+        line: usize,
+        msg: String,
+    },
+    /// A puzzle parameter that the day does not know or whose value is invalid.
+    InvalidParam { name: String, msg: String },
+    /// A new day could not be scaffolded, e.g. because it already exists.
+    Scaffold(String),
+    /// None of the benchmarked stages has a saved baseline to compare with, optionally of the
+    /// requested revision.
+    NoBaseline { revision: Option<String> },
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    pub fn malformed(msg: impl Into<String>) -> Self {
+        Error::Malformed(msg.into())
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingInput { path, source } => {
+                write!(f, "failed to read input '{}': {source}", path.display())
+            }
+            Error::WriteFailed { path, source } => {
+                write!(f, "failed to write '{}': {source}", path.display())
+            }
+            Error::EmptyInput => write!(f, "input is empty"),
+            Error::RaggedGrid {
+                row,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "grid row {row} has width {actual}, expected {expected} like the first row"
+            ),
+            Error::UnexpectedChar { char, line, column } => {
+                write!(
+                    f,
+                    "unexpected character {char:?} at line {line}, column {column}"
+                )
+            }
+            Error::InvalidNumber { value, source } => {
+                write!(f, "invalid number {value:?}: {source}")
+            }
+            Error::Malformed(msg) => write!(f, "malformed input: {msg}"),
+            Error::Syntax {
+                line,
+                column,
+                expected,
+                found,
+            } => write!(
+                f,
+                "expected {expected} at line {line}, column {column}, found {found:?}"
+            ),
+            Error::InvalidRecord { record, line, msg } => {
+                write!(f, "record {record} at line {line}: {msg}")
+            }
+            Error::InvalidParam { name, msg } => write!(f, "parameter '{name}': {msg}"),
+            Error::Scaffold(msg) => write!(f, "cannot create day: {msg}"),
+            Error::NoBaseline { revision: None } => {
+                write!(f, "no saved baseline to compare with")
+            }
+            Error::NoBaseline {
+                revision: Some(revision),
+            } => write!(
+                f,
+                "no saved baseline of revision '{revision}' to compare with"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::MissingInput { source, .. } | Error::WriteFailed { source, .. } => Some(source),
+            Error::InvalidNumber { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
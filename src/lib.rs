@@ -1,3 +1,19 @@
-#![feature(trait_alias)]
+// lets the derive macros refer to `::adventofcode2024` from within this crate as well
+extern crate self as adventofcode2024;
+
+pub mod answers;
+pub mod baseline;
+pub mod bench;
+pub mod days;
+pub mod error;
 pub mod matrix;
+pub mod params;
+pub mod parser;
+pub mod point;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+pub mod tokenizer;
 pub mod util;
+
+pub use error::{Error, Result};
@@ -33,6 +33,16 @@ impl Direction {
             Right => Left,
         }
     }
+    /// The step to the neighbouring cell in this direction, rows grow downwards.
+    pub const fn offset(&self) -> MatrixIdxOffset {
+        use Direction::*;
+        match self {
+            Up => MatrixIdxOffset::new(-1, 0),
+            Right => MatrixIdxOffset::new(0, 1),
+            Down => MatrixIdxOffset::new(1, 0),
+            Left => MatrixIdxOffset::new(0, -1),
+        }
+    }
 }
 
 pub const ALL_DIRECTIONS: [Direction; 4] = [
@@ -51,98 +61,215 @@ impl MatrixIdx {
     pub fn new(row: usize, col: usize) -> MatrixIdx {
         MatrixIdx { row, col }
     }
+    /// `self` moved by `offset`, `None` if the row or column would become negative.
+    pub fn checked_add(&self, offset: &MatrixIdxOffset) -> Option<MatrixIdx> {
+        Some(MatrixIdx {
+            row: self.row.checked_add_signed(offset.rows.try_into().ok()?)?,
+            col: self.col.checked_add_signed(offset.cols.try_into().ok()?)?,
+        })
+    }
+    /// `self` moved against `offset`, `None` if the row or column would become negative.
+    pub fn checked_sub(&self, offset: &MatrixIdxOffset) -> Option<MatrixIdx> {
+        self.checked_add(&MatrixIdxOffset::new(
+            offset.rows.checked_neg()?,
+            offset.cols.checked_neg()?,
+        ))
+    }
+    /// `self` moved by `offset` on a grid of `(rows, cols)` whose opposite edges are connected.
+    pub fn wrapping_add_in(&self, offset: &MatrixIdxOffset, (rows, cols): (usize, usize)) -> Self {
+        let wrap = |pos: usize, delta: i64, len: usize| {
+            (pos as i128 + delta as i128).rem_euclid(len as i128) as usize
+        };
+        MatrixIdx {
+            row: wrap(self.row, offset.rows, rows),
+            col: wrap(self.col, offset.cols, cols),
+        }
+    }
+    /// Implements the operators with [`MatrixIdxOffset`], which must not leave the grid to the
+    /// top or left, use [`Self::checked_add`] where that can happen.
+    fn moved(&self, rows: i64, cols: i64) -> Self {
+        let row = self.row as i64 + rows;
+        let col = self.col as i64 + cols;
+        debug_assert!(
+            row >= 0 && col >= 0,
+            "{self:?} moved by ({rows}, {cols}) has a negative coordinate"
+        );
+        MatrixIdx {
+            row: row as usize,
+            col: col as usize,
+        }
+    }
 }
 
+mod bits;
+mod bounds;
+mod regions;
+pub mod search;
+mod sparse;
+mod transform;
+mod view;
+
+pub use bits::{BitGrid, CellBits, DirectionSet};
+pub use bounds::{OutOfBounds, OutOfBoundsView};
+pub use regions::{Components, Connectivity, Region};
+pub use sparse::SparseGrid;
+pub use view::MatrixView;
+
+use crate::error::Error;
+use crate::point::Point2;
+use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Display};
+use std::hash::Hash;
 use std::ops::{Add, Index, IndexMut, Sub};
+
+/// Derives [`FromChar`] and [`ToChar`] for enums whose variants are marked with their character.
+///
+/// ```
+/// use adventofcode2024::matrix::{FromChar, ToChar};
+///
+/// #[derive(Debug, PartialEq, FromChar, ToChar)]
+/// enum Tile {
+///     #[char('#')]
+///     Wall,
+///     #[char('.')]
+///     Free,
+/// }
+/// assert_eq!(Tile::try_from_char(&'#'), Some(Tile::Wall));
+/// assert_eq!(Tile::try_from_char(&'x'), None);
+/// assert_eq!(Tile::Free.to_char(), '.');
+/// ```
+///
+/// Using a character twice is a compile error:
+///
+/// ```compile_fail
+/// use adventofcode2024::matrix::FromChar;
+///
+/// #[derive(FromChar)]
+/// enum Tile {
+///     #[char('#')]
+///     Wall,
+///     #[char('#')]
+///     Box,
+/// }
+/// ```
+pub use adventofcode2024_derive::{FromChar, ToChar};
+
 pub trait FromChar: Sized {
     fn try_from_char(char: &char) -> Option<Self>;
 }
-pub trait MatrixElement = Clone + PartialEq;
-
-#[derive(Debug, PartialEq, Clone, Copy)]
-pub struct MatrixIdxOffset {
-    pub rows: i64,
-    pub cols: i64,
+/// The character a value is shown as, the reverse of [`FromChar`].
+pub trait ToChar {
+    fn to_char(&self) -> char;
 }
-impl MatrixIdxOffset {
-    pub const fn new(rows: i64, cols: i64) -> Self {
-        MatrixIdxOffset { rows, cols }
+impl ToChar for char {
+    fn to_char(&self) -> char {
+        *self
     }
 }
-
-pub struct IdxValueIterator<'a, T: MatrixElement> {
-    count: usize,
-    matrix: &'a Matrix<T>,
-}
-impl<'a, T: MatrixElement> IdxValueIterator<'a, T> {
-    fn new(matrix: &'a Matrix<T>) -> Self {
-        Self { count: 0, matrix }
+/// Empty cells are shown as `.`.
+impl<T: ToChar> ToChar for Option<T> {
+    fn to_char(&self) -> char {
+        self.as_ref().map_or('.', T::to_char)
     }
 }
+/// Values that can be stored in a [`Matrix`], implemented for every `Clone + PartialEq` type.
+pub trait MatrixElement: Clone + PartialEq {}
+impl<T: Clone + PartialEq> MatrixElement for T {}
 
-impl<'a, T: MatrixElement> Iterator for IdxValueIterator<'a, T> {
-    type Item = (MatrixIdx, &'a T);
-    fn next(&mut self) -> Option<Self::Item> {
-        let count = self.count;
-        let ret = self.matrix.idx_value_from_linidx(count);
-        self.count += 1;
-        ret
-    }
-}
-pub struct RowIterator<'a, T: MatrixElement> {
-    count: usize,
-    matrix: &'a Matrix<T>,
-}
+/// Reading the cells of a [`Matrix`], a [`MatrixView`], an [`OutOfBoundsView`] or a
+/// [`SparseGrid`], which lets neighbour iteration and the grid searches of [`search`] work on all
+/// of them.
+pub trait Grid {
+    /// The position of a cell.
+    type Idx: Copy + Eq + Hash;
+    type Item;
+    /// The value at `idx`, `None` outside of the grid or for empty cells.
+    fn get(&self, idx: &Self::Idx) -> Option<&Self::Item>;
+    /// `idx` moved by `offset`, `None` if that leaves the grid.
+    fn offset_idx(&self, idx: &Self::Idx, offset: &MatrixIdxOffset) -> Option<Self::Idx>;
 
-impl<'a, T: MatrixElement> RowIterator<'a, T> {
-    fn new(matrix: &'a Matrix<T>) -> Self {
-        Self { count: 0, matrix }
+    /// The orthogonal neighbours of `idx` holding a value, in the order of [`ALL_DIRECTIONS`].
+    fn neighbors4<'a>(
+        &'a self,
+        idx: &Self::Idx,
+    ) -> impl Iterator<Item = (Direction, Self::Idx, &'a Self::Item)> + use<'a, Self> {
+        let idx = *idx;
+        ALL_DIRECTIONS.into_iter().filter_map(move |dir| {
+            let next = self.offset_idx(&idx, &dir.offset())?;
+            Some((dir, next, self.get(&next)?))
+        })
+    }
+    /// The orthogonal and diagonal neighbours of `idx` holding a value, in the order of
+    /// [`ALL_OFFSETS8`].
+    fn neighbors8<'a>(
+        &'a self,
+        idx: &Self::Idx,
+    ) -> impl Iterator<Item = (MatrixIdxOffset, Self::Idx, &'a Self::Item)> + use<'a, Self> {
+        let idx = *idx;
+        ALL_OFFSETS8.into_iter().filter_map(move |offset| {
+            let next = self.offset_idx(&idx, &offset)?;
+            Some((offset, next, self.get(&next)?))
+        })
+    }
+    /// The cells from `start` on in steps of `step`, until one is outside of the grid or empty.
+    ///
+    /// The ray never ends on a grid where every cell has a value, i.e. a torus, an unbounded
+    /// grid or a view with [`OutOfBounds::Clamp`] or [`OutOfBounds::Default`], nor with a zero
+    /// `step`.
+    fn ray<'a>(
+        &'a self,
+        start: Self::Idx,
+        step: MatrixIdxOffset,
+    ) -> impl Iterator<Item = (Self::Idx, &'a Self::Item)> + use<'a, Self> {
+        let mut next = Some(start);
+        std::iter::from_fn(move || {
+            let idx = next?;
+            let value = self.get(&idx)?;
+            next = self.offset_idx(&idx, &step);
+            Some((idx, value))
+        })
+    }
+    /// Like [`Grid::ray`], but also stopping before the first cell for which `stop` holds, e.g.
+    /// the first wall in the line of sight.
+    fn ray_until<'a, F>(
+        &'a self,
+        start: Self::Idx,
+        step: MatrixIdxOffset,
+        stop: F,
+    ) -> impl Iterator<Item = (Self::Idx, &'a Self::Item)> + use<'a, Self, F>
+    where
+        F: Fn(&Self::Item) -> bool + 'a,
+    {
+        self.ray(start, step)
+            .take_while(move |(_, value)| !stop(value))
     }
 }
 
-impl<'a, T: MatrixElement> Iterator for RowIterator<'a, T> {
-    type Item = &'a [T];
-    fn next(&mut self) -> Option<Self::Item> {
-        let width = self.matrix.width();
-        let count = self.count;
-        self.count += 1;
-        if count < self.matrix.height() {
-            Some(
-                self.matrix
-                    .data
-                    .get((count * width)..((count + 1) * width))
-                    .unwrap(),
-            )
-        } else {
-            None
-        }
-    }
-}
-pub struct IdxIterator<'a, T: MatrixElement> {
-    count: usize,
-    matrix: &'a Matrix<T>,
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct MatrixIdxOffset {
+    pub rows: i64,
+    pub cols: i64,
 }
-
-impl<'a, T: MatrixElement> IdxIterator<'a, T> {
-    fn new(matrix: &'a Matrix<T>) -> Self {
-        Self { count: 0, matrix }
+impl MatrixIdxOffset {
+    pub const fn new(rows: i64, cols: i64) -> Self {
+        MatrixIdxOffset { rows, cols }
     }
 }
 
-impl<T: MatrixElement> Iterator for IdxIterator<'_, T> {
-    type Item = MatrixIdx;
-    fn next(&mut self) -> Option<Self::Item> {
-        let count = self.count;
-        self.count += 1;
-        if count < self.matrix.data.len() {
-            Some(self.matrix.idx_from_lin(count))
-        } else {
-            None
-        }
-    }
-}
+/// Offsets to the eight surrounding cells, clockwise starting with the one above.
+pub const ALL_OFFSETS8: [MatrixIdxOffset; 8] = [
+    MatrixIdxOffset::new(-1, 0),
+    MatrixIdxOffset::new(-1, 1),
+    MatrixIdxOffset::new(0, 1),
+    MatrixIdxOffset::new(1, 1),
+    MatrixIdxOffset::new(1, 0),
+    MatrixIdxOffset::new(1, -1),
+    MatrixIdxOffset::new(0, -1),
+    MatrixIdxOffset::new(-1, -1),
+];
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Matrix<T>
 where
     T: MatrixElement,
@@ -156,21 +283,10 @@ where
     T: MatrixElement,
 {
     pub fn find(&self, value: &T) -> Option<MatrixIdx> {
-        let and_then = self
-            .data
-            .iter()
-            .position(|val| val == value)
-            .and_then(|linidx| self.try_idx_from_lin(linidx));
-        and_then
+        self.as_view().find(value)
     }
     pub fn find_all(&self, value: &T) -> Vec<MatrixIdx> {
-        let mut ret = Vec::new();
-        for (i, val) in self.idx_value_iter() {
-            if val == value {
-                ret.push(i);
-            }
-        }
-        ret
+        self.as_view().find_all(value)
     }
     pub fn get(&self, idx: &MatrixIdx) -> Option<&T> {
         self.try_linidx(idx).and_then(|idx| self.get_lin(idx))
@@ -182,34 +298,15 @@ where
         self.try_linidx(index)
             .and_then(|index| self.data.get_mut(index))
     }
-    pub fn get_wrapped(&self, idx: &MatrixIdx) -> &T {
-        let MatrixIdx { row, col } = idx;
-        let (height, width) = self.shape();
-
-        &self[MatrixIdx {
-            row: row.rem_euclid(height),
-            col: col.rem_euclid(width),
-        }]
-    }
     pub fn height(&self) -> usize {
         self.data.len() / self.width
     }
-    fn idx_from_lin(&self, linidx: usize) -> MatrixIdx {
-        MatrixIdx {
-            row: (linidx / self.width),
-            col: (linidx % self.width),
-        }
-    }
-    fn idx_value_from_linidx(&self, linidx: usize) -> Option<(MatrixIdx, &T)> {
-        self.get_lin(linidx)
-            .map(|elem| (self.idx_from_lin(linidx), elem))
-    }
-    pub fn idx_value_iter(&'a self) -> IdxValueIterator<'a, T> {
-        IdxValueIterator::new(self)
+    pub fn idx_value_iter(&'a self) -> impl Iterator<Item = (MatrixIdx, &'a T)> + 'a {
+        self.as_view().idx_value_iter()
     }
     /// Returns the indizes of this [`Matrix<T>`].
-    pub fn indizes(&'a self) -> IdxIterator<'a, T> {
-        IdxIterator::new(self)
+    pub fn indizes(&self) -> impl Iterator<Item = MatrixIdx> {
+        self.as_view().indizes()
     }
     pub fn is_valid_idx(&self, idx: &MatrixIdx) -> bool {
         let h = self.height();
@@ -218,6 +315,19 @@ where
         idx.col < w && idx.row < h
     }
 
+    /// `idx` moved by `offset`, `None` if that leaves the matrix.
+    pub fn offset_idx(&self, idx: &MatrixIdx, offset: &MatrixIdxOffset) -> Option<MatrixIdx> {
+        idx.checked_add(offset)
+            .filter(|next| self.is_valid_idx(next))
+    }
+
+    /// The index of `point`, `None` if it lies outside of the matrix.
+    pub fn point_idx(&self, point: Point2) -> Option<MatrixIdx> {
+        MatrixIdx::try_from(point)
+            .ok()
+            .filter(|idx| self.is_valid_idx(idx))
+    }
+
     pub fn linidx(&self, idx: &MatrixIdx) -> usize {
         idx.row * self.width + idx.col
     }
@@ -226,51 +336,48 @@ where
     pub fn from(data: Vec<T>, width: usize) -> Self {
         Self { data, width }
     }
-    fn shape(&self) -> (usize, usize) {
-        (self.height(), self.width)
+    /// Creates a matrix of the given size holding `f` of every index, filled row by row.
+    pub fn from_fn(height: usize, width: usize, mut f: impl FnMut(MatrixIdx) -> T) -> Self {
+        let data = (0..height * width)
+            .map(|linidx| f(MatrixIdx::new(linidx / width, linidx % width)))
+            .collect();
+        Self { data, width }
     }
-    pub fn try_from_str(input: &str) -> Option<Self>
+    pub fn try_from_str(input: &str) -> Result<Self, Error>
     where
         T: FromChar,
     {
-        let mut data = Vec::new();
-        let mut width: Option<usize> = None;
-        for line in input.lines() {
-            let mut line_len = 0;
-            for c in line.chars() {
-                if let Some(a) = T::try_from_char(&c) {
-                    data.push(a);
-                    line_len += 1;
-                }
-            }
-            let width = width.get_or_insert(line_len);
-            if *width != line_len {
-                return None;
-            }
-        }
-        width.map(|width| Self { data, width })
+        Self::try_from_str_with(input, T::try_from_char)
     }
-    pub fn try_from_str_with(input: &str, parse: fn(&char) -> Option<T>) -> Option<Self> {
+    /// Parses one row per line, rejecting characters `parse` does not accept and rows whose width
+    /// differs from the first one.
+    pub fn try_from_str_with(input: &str, parse: fn(&char) -> Option<T>) -> Result<Self, Error> {
         let mut data = Vec::new();
         let mut width: Option<usize> = None;
-        for line in input.lines() {
+        for (row, line) in input.lines().enumerate() {
             let mut line_len = 0;
-            for c in line.chars() {
-                if let Some(a) = parse(&c) {
-                    data.push(a);
-                    line_len += 1;
-                }
+            for (column, c) in line.chars().enumerate() {
+                let a = parse(&c).ok_or(Error::UnexpectedChar {
+                    char: c,
+                    line: row + 1,
+                    column: column + 1,
+                })?;
+                data.push(a);
+                line_len += 1;
             }
             let width = width.get_or_insert(line_len);
             if *width != line_len {
-                return None;
+                return Err(Error::RaggedGrid {
+                    row: row + 1,
+                    expected: *width,
+                    actual: line_len,
+                });
             }
         }
-        width.map(|width| Self { data, width })
-    }
-
-    fn try_idx_from_lin(&self, linidx: usize) -> Option<MatrixIdx> {
-        (linidx < self.data.len()).then(|| self.idx_from_lin(linidx))
+        match width {
+            Some(width) if width > 0 => Ok(Self { data, width }),
+            _ => Err(Error::EmptyInput),
+        }
     }
 
     pub fn try_linidx(&self, idx: &MatrixIdx) -> Option<usize> {
@@ -285,8 +392,95 @@ where
         self.width
     }
 
-    pub fn rows(&'a self) -> RowIterator<'a, T> {
-        RowIterator::new(self)
+    pub fn rows(&'a self) -> impl Iterator<Item = &'a [T]> + 'a {
+        self.as_view().rows()
+    }
+    /// Prepares printing the matrix with markers drawn over some cells, see [`Render`].
+    pub fn render(&'a self) -> Render<'a, T> {
+        self.as_view().render()
+    }
+}
+
+impl<T: MatrixElement> Grid for Matrix<T> {
+    type Idx = MatrixIdx;
+    type Item = T;
+    fn get(&self, idx: &MatrixIdx) -> Option<&T> {
+        Matrix::get(self, idx)
+    }
+    fn offset_idx(&self, idx: &MatrixIdx, offset: &MatrixIdxOffset) -> Option<MatrixIdx> {
+        Matrix::offset_idx(self, idx, offset)
+    }
+}
+
+/// Prints one line per row, see [`Matrix::render`] for drawing a path or markers on top.
+impl<T> Display for Matrix<T>
+where
+    T: MatrixElement + ToChar,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_view().fmt(f)
+    }
+}
+
+/// A [`Matrix`] or [`MatrixView`] with markers drawn over some of its cells, printed with [`Display`].
+///
+/// ```
+/// use adventofcode2024::matrix::{Matrix, MatrixIdx};
+///
+/// let grid = Matrix::try_from_str_with("...\n.#.", |c| Some(*c)).unwrap();
+/// let path = [MatrixIdx::new(0, 0), MatrixIdx::new(0, 1), MatrixIdx::new(0, 2)];
+/// let shown = grid.render().mark(&path, 'o').mark([MatrixIdx::new(1, 2)], 'E');
+/// assert_eq!(shown.to_string(), "ooo\n.#E");
+/// ```
+pub struct Render<'a, T: MatrixElement> {
+    view: MatrixView<'a, T>,
+    marks: HashMap<MatrixIdx, char>,
+    highlights: HashSet<MatrixIdx>,
+}
+
+impl<T: MatrixElement> Render<'_, T> {
+    /// Shows `marker` instead of the values of `cells`, replacing earlier markers.
+    pub fn mark<I>(mut self, cells: impl IntoIterator<Item = I>, marker: char) -> Self
+    where
+        I: Borrow<MatrixIdx>,
+    {
+        for idx in cells {
+            self.marks.insert(*idx.borrow(), marker);
+        }
+        self
+    }
+    /// Shows `cells` in inverse video on a terminal, keeping their character.
+    pub fn highlight<I>(mut self, cells: impl IntoIterator<Item = I>) -> Self
+    where
+        I: Borrow<MatrixIdx>,
+    {
+        self.highlights
+            .extend(cells.into_iter().map(|idx| *idx.borrow()));
+        self
+    }
+}
+
+impl<T> Display for Render<'_, T>
+where
+    T: MatrixElement + ToChar,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, value) in self.view.idx_value_iter() {
+            if idx.col == 0 && idx.row > 0 {
+                writeln!(f)?;
+            }
+            let c = self
+                .marks
+                .get(&idx)
+                .copied()
+                .unwrap_or_else(|| value.to_char());
+            if self.highlights.contains(&idx) {
+                write!(f, "\x1b[7m{c}\x1b[0m")?;
+            } else {
+                write!(f, "{c}")?;
+            }
+        }
+        Ok(())
     }
 }
 
@@ -366,72 +560,165 @@ impl Sub<MatrixIdx> for MatrixIdx {
 impl Sub<MatrixIdxOffset> for &MatrixIdx {
     type Output = MatrixIdx;
     fn sub(self, rhs: MatrixIdxOffset) -> Self::Output {
-        MatrixIdx {
-            col: (self.col as i64 - rhs.cols) as usize,
-            row: (self.row as i64 - rhs.rows) as usize,
-        }
+        self.moved(-rhs.rows, -rhs.cols)
     }
 }
 impl Sub<&MatrixIdxOffset> for &MatrixIdx {
     type Output = MatrixIdx;
     fn sub(self, rhs: &MatrixIdxOffset) -> Self::Output {
-        MatrixIdx {
-            col: (self.col as i64 - rhs.cols) as usize,
-            row: (self.row as i64 - rhs.rows) as usize,
-        }
+        self.moved(-rhs.rows, -rhs.cols)
     }
 }
 impl Sub<MatrixIdxOffset> for MatrixIdx {
     type Output = MatrixIdx;
     fn sub(self, rhs: MatrixIdxOffset) -> Self::Output {
-        MatrixIdx {
-            col: (self.col as i64 - rhs.cols) as usize,
-            row: (self.row as i64 - rhs.rows) as usize,
-        }
+        self.moved(-rhs.rows, -rhs.cols)
     }
 }
 impl Sub<&MatrixIdxOffset> for MatrixIdx {
     type Output = MatrixIdx;
     fn sub(self, rhs: &MatrixIdxOffset) -> Self::Output {
-        MatrixIdx {
-            col: (self.col as i64 - rhs.cols) as usize,
-            row: (self.row as i64 - rhs.rows) as usize,
-        }
+        self.moved(-rhs.rows, -rhs.cols)
     }
 }
 impl Add<&MatrixIdxOffset> for &MatrixIdx {
     type Output = MatrixIdx;
     fn add(self, rhs: &MatrixIdxOffset) -> Self::Output {
-        MatrixIdx {
-            col: (self.col as i64 + rhs.cols) as usize,
-            row: (self.row as i64 + rhs.rows) as usize,
-        }
+        self.moved(rhs.rows, rhs.cols)
     }
 }
 impl Add<MatrixIdxOffset> for &MatrixIdx {
     type Output = MatrixIdx;
-    fn add(self, rhs:MatrixIdxOffset) -> Self::Output {
-        MatrixIdx {
-            col: (self.col as i64 + rhs.cols) as usize,
-            row: (self.row as i64 + rhs.rows) as usize,
-        }
+    fn add(self, rhs: MatrixIdxOffset) -> Self::Output {
+        self.moved(rhs.rows, rhs.cols)
     }
 }
 impl Add<&MatrixIdxOffset> for MatrixIdx {
     type Output = MatrixIdx;
     fn add(self, rhs: &MatrixIdxOffset) -> Self::Output {
-        MatrixIdx {
-            col: (self.col as i64 + rhs.cols) as usize,
-            row: (self.row as i64 + rhs.rows) as usize,
-        }
+        self.moved(rhs.rows, rhs.cols)
     }
 }
 impl Add<MatrixIdxOffset> for MatrixIdx {
     type Output = MatrixIdx;
     fn add(self, rhs: MatrixIdxOffset) -> Self::Output {
-        MatrixIdx {
-            col: (self.col as i64 + rhs.cols) as usize,
-            row: (self.row as i64 + rhs.rows) as usize,
-        }
+        self.moved(rhs.rows, rhs.cols)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_try_from_str_errors() {
+        let parse = |c: &char| c.to_digit(10);
+        assert!(matches!(
+            Matrix::<u32>::try_from_str_with("123\n45\n678", parse),
+            Err(Error::RaggedGrid {
+                row: 2,
+                expected: 3,
+                actual: 2
+            })
+        ));
+        assert!(matches!(
+            Matrix::<u32>::try_from_str_with("123\n4x6", parse),
+            Err(Error::UnexpectedChar {
+                char: 'x',
+                line: 2,
+                column: 2
+            })
+        ));
+        assert!(matches!(
+            Matrix::<u32>::try_from_str_with("", parse),
+            Err(Error::EmptyInput)
+        ));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Matrix::from((0..6).collect(), 3);
+        let corner: Vec<_> = grid.neighbors4(&MatrixIdx::new(0, 0)).collect();
+        assert_eq!(
+            corner,
+            vec![
+                (Direction::Right, MatrixIdx::new(0, 1), &1),
+                (Direction::Down, MatrixIdx::new(1, 0), &3),
+            ]
+        );
+        let values: Vec<_> = grid
+            .neighbors8(&MatrixIdx::new(1, 1))
+            .map(|(_, _, value)| *value)
+            .collect();
+        assert_eq!(values, vec![1, 2, 5, 3, 0]);
+        assert_eq!(grid.neighbors4(&MatrixIdx::new(1, 2)).count(), 2);
+        assert_eq!(grid.neighbors8(&MatrixIdx::new(5, 5)).count(), 0);
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = Matrix::try_from_str_with("abcd\nefgh\nijkl", |c| Some(*c)).unwrap();
+        let cells = |ray: &mut dyn Iterator<Item = (MatrixIdx, &char)>| -> String {
+            ray.map(|(_, c)| *c).collect()
+        };
+        let start = MatrixIdx::new(0, 0);
+        assert_eq!(
+            cells(&mut grid.ray(start, MatrixIdxOffset::new(1, 1))),
+            "afk"
+        );
+        assert_eq!(
+            cells(&mut grid.ray(start, MatrixIdxOffset::new(0, 2))),
+            "ac"
+        );
+        assert_eq!(
+            cells(&mut grid.ray(start, MatrixIdxOffset::new(-1, 0))),
+            "a"
+        );
+        assert_eq!(
+            cells(&mut grid.ray(MatrixIdx::new(5, 0), MatrixIdxOffset::new(-1, 0))),
+            ""
+        );
+        let sight = grid.ray_until(MatrixIdx::new(2, 3), Direction::Left.offset(), |c| {
+            *c == 'i'
+        });
+        assert_eq!(sight.last().map(|(idx, _)| idx), Some(MatrixIdx::new(2, 1)));
+    }
+
+    #[test]
+    fn test_idx_arithmetic() {
+        let idx = MatrixIdx::new(1, 2);
+        let up_left = MatrixIdxOffset::new(-1, -1);
+        assert_eq!(idx.checked_add(&up_left), Some(MatrixIdx::new(0, 1)));
+        assert_eq!(idx.checked_add(&MatrixIdxOffset::new(-2, 0)), None);
+        assert_eq!(idx.checked_sub(&MatrixIdxOffset::new(0, 3)), None);
+        assert_eq!(idx.checked_sub(&up_left), Some(MatrixIdx::new(2, 3)));
+        assert_eq!(
+            idx.wrapping_add_in(&MatrixIdxOffset::new(-3, 9), (3, 4)),
+            MatrixIdx::new(1, 3)
+        );
+        assert_eq!(idx + up_left, MatrixIdx::new(0, 1));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "negative coordinate")]
+    fn test_idx_underflow() {
+        let _ = MatrixIdx::new(0, 2) + Direction::Up.offset();
+    }
+
+    #[test]
+    fn test_render() {
+        let grid = Matrix::try_from_str_with("#..\n.#.", |c| Some(*c)).unwrap();
+        assert_eq!(grid.to_string(), "#..\n.#.");
+        let path = [MatrixIdx::new(0, 1), MatrixIdx::new(0, 2)];
+        let shown = grid
+            .render()
+            .mark(path, 'x')
+            .mark([MatrixIdx::new(0, 2)], 'E')
+            .highlight([MatrixIdx::new(1, 1)]);
+        assert_eq!(shown.to_string(), "#xE\n.\x1b[7m#\x1b[0m.");
+        // markers outside of the matrix are not shown
+        let shown = grid.render().mark([MatrixIdx::new(5, 5)], 'x');
+        assert_eq!(shown.to_string(), "#..\n.#.");
     }
 }
@@ -0,0 +1,324 @@
+//! Sets of matrix cells packed into bits, see [`BitGrid`].
+use std::marker::PhantomData;
+
+use super::{Direction, Matrix, MatrixElement, MatrixIdx, ALL_DIRECTIONS};
+
+/// Values stored in a few bits per cell of a [`BitGrid`].
+pub trait CellBits: Copy {
+    /// The number of bits of a cell, which has to divide 64.
+    const BITS: usize;
+    fn to_bits(self) -> u64;
+    fn from_bits(bits: u64) -> Self;
+}
+
+impl CellBits for bool {
+    const BITS: usize = 1;
+    fn to_bits(self) -> u64 {
+        self as u64
+    }
+    fn from_bits(bits: u64) -> Self {
+        bits != 0
+    }
+}
+
+/// A set of directions, e.g. the ones a cell has been left in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DirectionSet(u8);
+
+impl DirectionSet {
+    pub const EMPTY: DirectionSet = DirectionSet(0);
+
+    fn bit(dir: Direction) -> u8 {
+        1 << dir as u8
+    }
+    /// Adds `dir`, returning whether it was missing.
+    pub fn insert(&mut self, dir: Direction) -> bool {
+        let missing = !self.contains(dir);
+        self.0 |= Self::bit(dir);
+        missing
+    }
+    /// Removes `dir`, returning whether it was there.
+    pub fn remove(&mut self, dir: Direction) -> bool {
+        let present = self.contains(dir);
+        self.0 &= !Self::bit(dir);
+        present
+    }
+    pub fn contains(&self, dir: Direction) -> bool {
+        self.0 & Self::bit(dir) != 0
+    }
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+    /// The directions in the set, in the order of [`ALL_DIRECTIONS`].
+    pub fn iter(&self) -> impl Iterator<Item = Direction> {
+        let set = *self;
+        ALL_DIRECTIONS
+            .into_iter()
+            .filter(move |dir| set.contains(*dir))
+    }
+}
+
+impl From<Direction> for DirectionSet {
+    fn from(dir: Direction) -> Self {
+        DirectionSet(Self::bit(dir))
+    }
+}
+
+impl FromIterator<Direction> for DirectionSet {
+    fn from_iter<I: IntoIterator<Item = Direction>>(iter: I) -> Self {
+        let mut set = DirectionSet::EMPTY;
+        for dir in iter {
+            set.insert(dir);
+        }
+        set
+    }
+}
+
+impl CellBits for DirectionSet {
+    const BITS: usize = 4;
+    fn to_bits(self) -> u64 {
+        self.0 as u64
+    }
+    fn from_bits(bits: u64) -> Self {
+        DirectionSet(bits as u8)
+    }
+}
+
+/// A set of the cells of a matrix, packed into one bit per cell, or a [`DirectionSet`] per cell
+/// to remember the direction a cell was visited in.
+///
+/// Unlike a `HashSet<MatrixIdx>` inserting and looking up never hashes, and [`BitGrid::clear`]
+/// keeps the memory for the next run of a simulation.
+///
+/// ```
+/// use adventofcode2024::matrix::{BitGrid, Direction, DirectionSet, MatrixIdx};
+///
+/// let mut visited: BitGrid = BitGrid::new(3, 4);
+/// assert!(visited.insert(&MatrixIdx::new(1, 2)));
+/// assert!(!visited.insert(&MatrixIdx::new(1, 2)));
+/// assert_eq!(visited.count(), 1);
+///
+/// let mut walked: BitGrid<DirectionSet> = BitGrid::new(3, 4);
+/// walked.insert(&MatrixIdx::new(0, 0), Direction::Up);
+/// assert!(!walked.contains(&MatrixIdx::new(0, 0), Direction::Down));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitGrid<T: CellBits = bool> {
+    words: Vec<u64>,
+    height: usize,
+    width: usize,
+    cells: PhantomData<T>,
+}
+
+impl<T: CellBits> BitGrid<T> {
+    /// An empty grid of `height` rows and `width` columns.
+    pub fn new(height: usize, width: usize) -> Self {
+        assert!(64 % T::BITS == 0, "cells must not span two words");
+        Self {
+            words: vec![0; (height * width * T::BITS).div_ceil(64)],
+            height,
+            width,
+            cells: PhantomData,
+        }
+    }
+    /// An empty grid of the same shape as `matrix`.
+    pub fn like<U: MatrixElement>(matrix: &Matrix<U>) -> Self {
+        Self::new(matrix.height(), matrix.width())
+    }
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn is_valid_idx(&self, idx: &MatrixIdx) -> bool {
+        idx.row < self.height && idx.col < self.width
+    }
+    /// The word holding the cell with the linear index `linidx` and the shift of the cell in it.
+    fn position(linidx: usize) -> (usize, usize) {
+        let bit = linidx * T::BITS;
+        (bit / 64, bit % 64)
+    }
+    fn mask() -> u64 {
+        (1 << T::BITS) - 1
+    }
+    fn bits(&self, linidx: usize) -> u64 {
+        let (word, shift) = Self::position(linidx);
+        (self.words[word] >> shift) & Self::mask()
+    }
+    fn try_linidx(&self, idx: &MatrixIdx) -> Option<usize> {
+        self.is_valid_idx(idx)
+            .then(|| idx.row * self.width + idx.col)
+    }
+    fn linidx(&self, idx: &MatrixIdx) -> usize {
+        self.try_linidx(idx).unwrap_or_else(|| {
+            panic!(
+                "{idx:?} is outside of the {}x{} grid",
+                self.height, self.width
+            )
+        })
+    }
+    /// The value of the cell at `idx`, `None` outside of the grid.
+    pub fn get(&self, idx: &MatrixIdx) -> Option<T> {
+        self.try_linidx(idx)
+            .map(|linidx| T::from_bits(self.bits(linidx)))
+    }
+    /// Replaces the value of the cell at `idx`, which has to be inside of the grid.
+    pub fn set(&mut self, idx: &MatrixIdx, value: T) {
+        let (word, shift) = Self::position(self.linidx(idx));
+        self.words[word] &= !(Self::mask() << shift);
+        self.words[word] |= value.to_bits() << shift;
+    }
+    /// Adds the bits of `value` to the cell at `idx`, returning whether any of them was missing.
+    fn insert_bits(&mut self, idx: &MatrixIdx, value: T) -> bool {
+        let (word, shift) = Self::position(self.linidx(idx));
+        let bits = value.to_bits() << shift;
+        let missing = self.words[word] & bits != bits;
+        self.words[word] |= bits;
+        missing
+    }
+    /// Whether the cell at `idx` has all bits of `value`, `false` outside of the grid.
+    fn contains_bits(&self, idx: &MatrixIdx, value: T) -> bool {
+        let bits = value.to_bits();
+        self.try_linidx(idx)
+            .is_some_and(|linidx| self.bits(linidx) & bits == bits)
+    }
+    /// The number of bits set, i.e. the number of cells or of cells and directions.
+    pub fn count(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+    /// The number of cells with any bit set.
+    pub fn count_cells(&self) -> usize {
+        // the lowest bit of every cell
+        let lowest = (0..64 / T::BITS).fold(0u64, |mask, cell| mask | 1 << (cell * T::BITS));
+        self.words
+            .iter()
+            .map(|&word| {
+                let any = (0..T::BITS).fold(0, |any, bit| any | word >> bit);
+                (any & lowest).count_ones() as usize
+            })
+            .sum()
+    }
+    /// Adds the bits set in `other`, which has to have the same shape.
+    pub fn union_with(&mut self, other: &Self) {
+        self.assert_same_shape(other);
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word |= other;
+        }
+    }
+    /// Keeps only the bits also set in `other`, which has to have the same shape.
+    pub fn intersect_with(&mut self, other: &Self) {
+        self.assert_same_shape(other);
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word &= other;
+        }
+    }
+    fn assert_same_shape(&self, other: &Self) {
+        assert_eq!(
+            (self.height, self.width),
+            (other.height, other.width),
+            "grids of different shape"
+        );
+    }
+    /// Empties every cell, keeping the memory.
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+    /// The cells with any bit set, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (MatrixIdx, T)> + '_ {
+        (0..self.height * self.width).filter_map(|linidx| {
+            let bits = self.bits(linidx);
+            let idx = MatrixIdx::new(linidx / self.width, linidx % self.width);
+            (bits != 0).then(|| (idx, T::from_bits(bits)))
+        })
+    }
+}
+
+impl BitGrid<bool> {
+    /// Adds the cell at `idx`, which has to be inside of the grid, returning whether it was
+    /// missing.
+    pub fn insert(&mut self, idx: &MatrixIdx) -> bool {
+        self.insert_bits(idx, true)
+    }
+    /// Removes the cell at `idx`, returning whether it was there.
+    pub fn remove(&mut self, idx: &MatrixIdx) -> bool {
+        let present = self.contains(idx);
+        if present {
+            self.set(idx, false);
+        }
+        present
+    }
+    pub fn contains(&self, idx: &MatrixIdx) -> bool {
+        self.contains_bits(idx, true)
+    }
+}
+
+impl BitGrid<DirectionSet> {
+    /// Adds `dir` to the cell at `idx`, which has to be inside of the grid, returning whether it
+    /// was missing.
+    pub fn insert(&mut self, idx: &MatrixIdx, dir: Direction) -> bool {
+        self.insert_bits(idx, dir.into())
+    }
+    pub fn contains(&self, idx: &MatrixIdx, dir: Direction) -> bool {
+        self.contains_bits(idx, dir.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_bit_grid() {
+        // 70 cells span two words
+        let mut a: BitGrid = BitGrid::new(7, 10);
+        let cells = [(0, 0), (3, 4), (6, 3), (6, 9)].map(|(row, col)| MatrixIdx::new(row, col));
+        for idx in &cells {
+            assert!(a.insert(idx));
+        }
+        assert!(!a.insert(&cells[1]));
+        assert!(a.contains(&cells[3]) && !a.contains(&MatrixIdx::new(6, 8)));
+        assert!(!a.contains(&MatrixIdx::new(7, 0)));
+        assert_eq!(a.get(&MatrixIdx::new(0, 10)), None);
+        assert_eq!(a.iter().map(|(idx, _)| idx).collect::<Vec<_>>(), cells);
+
+        let mut b: BitGrid = BitGrid::new(7, 10);
+        b.insert(&cells[1]);
+        b.insert(&MatrixIdx::new(5, 5));
+        let mut union = a.clone();
+        union.union_with(&b);
+        assert_eq!(union.count(), 5);
+        a.intersect_with(&b);
+        assert_eq!(a.count(), 1);
+        assert!(b.remove(&MatrixIdx::new(5, 5)) && !b.remove(&MatrixIdx::new(5, 5)));
+        assert_eq!(a, b);
+        a.clear();
+        assert_eq!((a.count(), a.count_cells()), (0, 0));
+    }
+
+    #[test]
+    fn test_direction_grid() {
+        use Direction::*;
+        let set: DirectionSet = [Up, Left, Up].into_iter().collect();
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.iter().collect::<Vec<_>>(), [Up, Left]);
+
+        let mut walked: BitGrid<DirectionSet> = BitGrid::new(5, 5);
+        let idx = MatrixIdx::new(4, 4);
+        assert!(walked.insert(&idx, Up));
+        assert!(walked.insert(&idx, Right));
+        assert!(!walked.insert(&idx, Up));
+        assert!(walked.contains(&idx, Right) && !walked.contains(&idx, Down));
+        walked.insert(&MatrixIdx::new(0, 3), Left);
+        assert_eq!((walked.count(), walked.count_cells()), (3, 2));
+        assert_eq!(walked.get(&idx), Some([Up, Right].into_iter().collect()));
+        walked.set(&idx, Down.into());
+        assert_eq!(walked.count(), 2);
+    }
+}
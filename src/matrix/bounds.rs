@@ -0,0 +1,189 @@
+//! Reading a [`Matrix`] beyond its edges, see [`OutOfBounds`].
+use std::ops::Index;
+
+use super::{Grid, Matrix, MatrixElement, MatrixIdx, MatrixIdxOffset, MatrixView};
+use crate::point::{Point2, Vec2};
+
+/// What lies outside of a grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OutOfBounds<T> {
+    /// Nothing, stepping over an edge leaves the grid.
+    None,
+    /// The grid is a torus, stepping over an edge enters it again on the opposite side.
+    Wrap,
+    /// Every cell outside repeats the nearest cell on the edge.
+    Clamp,
+    /// Every cell outside holds the same value, e.g. a wall around a maze.
+    Default(T),
+    /// The grid repeats endlessly in every direction.
+    Tile,
+}
+
+/// A [`MatrixView`] read with an [`OutOfBounds`] policy, indexed by points relative to its top
+/// left corner.
+///
+/// Except for [`OutOfBounds::None`] and [`OutOfBounds::Wrap`] the plane has no end, so searches
+/// on it need a goal or impassable cells around the start.
+///
+/// ```
+/// use adventofcode2024::matrix::{search, Matrix, OutOfBounds};
+/// use adventofcode2024::point::Point2;
+///
+/// let grid = Matrix::try_from_str_with("ab\ncd", |c| Some(*c)).unwrap();
+/// let torus = grid.out_of_bounds(OutOfBounds::Wrap);
+/// assert_eq!(torus[Point2::new(-1, 2)], 'b');
+/// let tiles = grid.out_of_bounds(OutOfBounds::Tile);
+/// let far = search::bfs(&tiles, Point2::new(0, 0), |_| true, Some(Point2::new(5, -3)));
+/// assert_eq!(far.distance(&Point2::new(5, -3)), Some(8));
+/// ```
+#[derive(Debug, Clone)]
+pub struct OutOfBoundsView<'a, T: MatrixElement> {
+    view: MatrixView<'a, T>,
+    policy: OutOfBounds<T>,
+}
+
+impl<T: MatrixElement> Matrix<T> {
+    /// The matrix read with `policy` beyond its edges.
+    pub fn out_of_bounds(&self, policy: OutOfBounds<T>) -> OutOfBoundsView<'_, T> {
+        self.as_view().out_of_bounds(policy)
+    }
+}
+
+impl<'a, T: MatrixElement> MatrixView<'a, T> {
+    /// The view read with `policy` beyond its edges.
+    pub fn out_of_bounds(&self, policy: OutOfBounds<T>) -> OutOfBoundsView<'a, T> {
+        OutOfBoundsView {
+            view: *self,
+            policy,
+        }
+    }
+}
+
+impl<T: MatrixElement> OutOfBoundsView<'_, T> {
+    pub fn height(&self) -> usize {
+        self.view.height()
+    }
+    pub fn width(&self) -> usize {
+        self.view.width()
+    }
+    pub fn policy(&self) -> &OutOfBounds<T> {
+        &self.policy
+    }
+    /// The cell of the underlying view read at `point`, `None` if it reads nothing or the
+    /// default value.
+    pub fn inner_idx(&self, point: &Point2) -> Option<MatrixIdx> {
+        let (height, width) = (self.height() as i64, self.width() as i64);
+        let inner = match self.policy {
+            OutOfBounds::None | OutOfBounds::Default(_) => *point,
+            OutOfBounds::Wrap | OutOfBounds::Tile => {
+                point.wrapped_in((self.height(), self.width()))
+            }
+            OutOfBounds::Clamp => {
+                Point2::new(point.x.clamp(0, width - 1), point.y.clamp(0, height - 1))
+            }
+        };
+        MatrixIdx::try_from(inner)
+            .ok()
+            .filter(|idx| self.view.is_valid_idx(idx))
+    }
+    /// The value at `point`, `None` only outside of the view with [`OutOfBounds::None`].
+    pub fn get(&self, point: &Point2) -> Option<&T> {
+        match (self.inner_idx(point), &self.policy) {
+            (Some(idx), _) => self.view.get(&idx),
+            (None, OutOfBounds::Default(value)) => Some(value),
+            (None, _) => None,
+        }
+    }
+    /// `point` moved by `offset`, which stays on the torus with [`OutOfBounds::Wrap`] and is
+    /// `None` outside of the view with [`OutOfBounds::None`].
+    pub fn offset_idx(&self, point: &Point2, offset: &MatrixIdxOffset) -> Option<Point2> {
+        let next = *point + Vec2::from(*offset);
+        match self.policy {
+            OutOfBounds::None => self.inner_idx(&next).map(|_| next),
+            OutOfBounds::Wrap => self.inner_idx(&next).map(Point2::from),
+            OutOfBounds::Clamp | OutOfBounds::Default(_) | OutOfBounds::Tile => Some(next),
+        }
+    }
+}
+
+impl<T: MatrixElement> Grid for OutOfBoundsView<'_, T> {
+    type Idx = Point2;
+    type Item = T;
+    fn get(&self, point: &Point2) -> Option<&T> {
+        OutOfBoundsView::get(self, point)
+    }
+    fn offset_idx(&self, point: &Point2, offset: &MatrixIdxOffset) -> Option<Point2> {
+        OutOfBoundsView::offset_idx(self, point, offset)
+    }
+}
+
+impl<T: MatrixElement> Index<Point2> for OutOfBoundsView<'_, T> {
+    type Output = T;
+    fn index(&self, point: Point2) -> &Self::Output {
+        self.get(&point)
+            .unwrap_or_else(|| panic!("{point:?} is outside of the grid"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::matrix::{search, Direction};
+
+    #[test]
+    fn test_policies() {
+        let grid = Matrix::try_from_str_with("abc\ndef", |c| Some(*c)).unwrap();
+        let outside = Point2::new(-1, 3);
+        let read = |policy| grid.out_of_bounds(policy).get(&outside).copied();
+        assert_eq!(read(OutOfBounds::None), None);
+        assert_eq!(read(OutOfBounds::Wrap), Some('f'));
+        assert_eq!(read(OutOfBounds::Clamp), Some('d'));
+        assert_eq!(read(OutOfBounds::Default('#')), Some('#'));
+        assert_eq!(read(OutOfBounds::Tile), Some('f'));
+        for policy in [OutOfBounds::None, OutOfBounds::Wrap, OutOfBounds::Clamp] {
+            assert_eq!(grid.out_of_bounds(policy)[Point2::new(1, 1)], 'e');
+        }
+
+        let corner = Point2::new(0, 0);
+        let up = Direction::Up.offset();
+        let torus = grid.out_of_bounds(OutOfBounds::Wrap);
+        assert_eq!(torus.offset_idx(&corner, &up), Some(Point2::new(0, 1)));
+        assert_eq!(torus.neighbors8(&corner).count(), 8);
+        let bounded = grid.out_of_bounds(OutOfBounds::None);
+        assert_eq!(bounded.offset_idx(&corner, &up), None);
+        assert_eq!(bounded.neighbors4(&corner).count(), 2);
+        let tiles = grid.out_of_bounds(OutOfBounds::Tile);
+        assert_eq!(tiles.offset_idx(&corner, &up), Some(Point2::new(0, -1)));
+
+        let view = grid.view(MatrixIdx::new(0, 1), 2, 2).unwrap();
+        let inner = view.out_of_bounds(OutOfBounds::Wrap);
+        assert_eq!(inner[Point2::new(2, 0)], 'b');
+        assert_eq!(
+            inner.inner_idx(&Point2::new(-1, -1)),
+            Some(MatrixIdx::new(1, 1))
+        );
+    }
+
+    #[test]
+    fn test_search_policies() {
+        let maze = Matrix::try_from_str_with("..#\n###\n..#", |c| Some(*c)).unwrap();
+        let open = |tile: &char| *tile == '.';
+        let (start, goal) = (Point2::new(0, 0), Point2::new(1, 2));
+
+        let bounded = maze.out_of_bounds(OutOfBounds::None);
+        assert_eq!(search::bfs(&bounded, start, open, Some(goal)).goal, None);
+        // wrapping around the top edge skips the wall
+        let torus = maze.out_of_bounds(OutOfBounds::Wrap);
+        let found = search::bfs(&torus, start, open, None);
+        assert_eq!(found.distance(&goal), Some(2));
+        assert_eq!(found.distances.len(), 4);
+        // stepping up enters the bottom row of the next tile, whose walls close it off
+        let tiles = maze.out_of_bounds(OutOfBounds::Tile);
+        let found = search::astar(&tiles, start, open, |_, _| 1, goal);
+        assert_eq!(found.goal, None);
+        assert_eq!(found.distance(&Point2::new(1, -1)), Some(2));
+        assert_eq!(found.distances.len(), 4);
+        let walled = maze.out_of_bounds(OutOfBounds::Default('#'));
+        assert_eq!(search::bfs(&walled, start, open, None).distances.len(), 2);
+    }
+}
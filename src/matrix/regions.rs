@@ -0,0 +1,223 @@
+//! Connected regions of a [`Matrix`], see [`Matrix::components`].
+use std::collections::{HashSet, VecDeque};
+
+use super::{
+    Direction, Matrix, MatrixElement, MatrixIdx, MatrixIdxOffset, ALL_DIRECTIONS, ALL_OFFSETS8,
+};
+
+/// Which neighbours of a cell belong to the same region.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Only the orthogonal neighbours.
+    Four,
+    /// The orthogonal and the diagonal neighbours.
+    Eight,
+}
+
+impl Connectivity {
+    fn offsets(&self) -> &'static [MatrixIdxOffset] {
+        const FOUR: [MatrixIdxOffset; 4] = [
+            Direction::Up.offset(),
+            Direction::Right.offset(),
+            Direction::Down.offset(),
+            Direction::Left.offset(),
+        ];
+        match self {
+            Connectivity::Four => &FOUR,
+            Connectivity::Eight => &ALL_OFFSETS8,
+        }
+    }
+    /// The connectivity of the cells around a region, which must not leak through where the
+    /// region is connected.
+    fn dual(&self) -> Self {
+        match self {
+            Connectivity::Four => Connectivity::Eight,
+            Connectivity::Eight => Connectivity::Four,
+        }
+    }
+}
+
+/// Size and shape of one region.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    /// The number of cells.
+    pub area: usize,
+    /// The number of cell edges between the region and the rest of the matrix or its outside.
+    pub perimeter: usize,
+    /// The number of straight sides of all borders, which equals the number of corners.
+    pub sides: usize,
+    /// The top left and the bottom right corner of the smallest rectangle holding the region.
+    pub bounds: (MatrixIdx, MatrixIdx),
+}
+
+/// The regions of a matrix, `labels` holds the index into `regions` of every cell.
+#[derive(Debug, Clone)]
+pub struct Components {
+    pub labels: Matrix<usize>,
+    pub regions: Vec<Region>,
+    connectivity: Connectivity,
+}
+
+impl Components {
+    /// The number of areas of other cells the region `label` encloses, counted within the
+    /// bounds of the region.
+    pub fn holes(&self, label: usize) -> usize {
+        self.labels
+            .count_holes(label, self.regions[label].bounds, self.connectivity)
+    }
+}
+
+impl<T: MatrixElement> Matrix<T> {
+    /// Splits the matrix into regions of neighbouring cells for which `eq` holds.
+    ///
+    /// ```
+    /// use adventofcode2024::matrix::{Connectivity, Matrix, MatrixIdx};
+    ///
+    /// let garden = Matrix::try_from_str_with("AAA\nABA\nAAA", |c| Some(*c)).unwrap();
+    /// let plots = garden.components(|a, b| a == b, Connectivity::Four);
+    /// let label = plots.labels[MatrixIdx::new(0, 0)];
+    /// let outer = &plots.regions[label];
+    /// assert_eq!((outer.area, outer.perimeter, outer.sides), (8, 16, 8));
+    /// assert_eq!(plots.holes(label), 1);
+    /// ```
+    pub fn components(
+        &self,
+        eq: impl Fn(&T, &T) -> bool,
+        connectivity: Connectivity,
+    ) -> Components {
+        let (labels, count) = self.label(connectivity, |a, b| eq(&self[a], &self[b]));
+        let mut regions: Vec<_> = (0..count)
+            .map(|_| Region {
+                area: 0,
+                perimeter: 0,
+                sides: 0,
+                bounds: (MatrixIdx::new(usize::MAX, usize::MAX), MatrixIdx::new(0, 0)),
+            })
+            .collect();
+        for (idx, &label) in labels.idx_value_iter() {
+            let inside = |offset: MatrixIdxOffset| {
+                labels
+                    .offset_idx(&idx, &offset)
+                    .is_some_and(|next| labels[next] == label)
+            };
+            let region = &mut regions[label];
+            region.area += 1;
+            let (min, max) = &mut region.bounds;
+            *min = MatrixIdx::new(min.row.min(idx.row), min.col.min(idx.col));
+            *max = MatrixIdx::new(max.row.max(idx.row), max.col.max(idx.col));
+            for dir in ALL_DIRECTIONS {
+                let (ahead, right) = (dir.offset(), dir.right().offset());
+                if !inside(ahead) {
+                    region.perimeter += 1;
+                }
+                // every corner of a border is seen from exactly one cell of the region
+                let diagonal =
+                    MatrixIdxOffset::new(ahead.rows + right.rows, ahead.cols + right.cols);
+                let convex = !inside(ahead) && !inside(right);
+                let concave = inside(ahead) && inside(right) && !inside(diagonal);
+                if convex || concave {
+                    region.sides += 1;
+                }
+            }
+        }
+        Components {
+            labels,
+            regions,
+            connectivity,
+        }
+    }
+
+    /// Labels the regions of cells connected where `joined` holds, numbered in the order of
+    /// their first cell, and returns the labels with the number of regions.
+    fn label(
+        &self,
+        connectivity: Connectivity,
+        joined: impl Fn(MatrixIdx, MatrixIdx) -> bool,
+    ) -> (Matrix<usize>, usize) {
+        const UNLABELED: usize = usize::MAX;
+        let mut labels = Matrix::from(vec![UNLABELED; self.data.len()], self.width);
+        let mut count = 0;
+        for start in self.indizes() {
+            if labels[start] != UNLABELED {
+                continue;
+            }
+            labels[start] = count;
+            let mut front = VecDeque::from([start]);
+            while let Some(idx) = front.pop_front() {
+                for offset in connectivity.offsets() {
+                    if let Some(next) = self.offset_idx(&idx, offset) {
+                        if labels[next] == UNLABELED && joined(idx, next) {
+                            labels[next] = count;
+                            front.push_back(next);
+                        }
+                    }
+                }
+            }
+            count += 1;
+        }
+        (labels, count)
+    }
+}
+
+impl Matrix<usize> {
+    /// The number of areas enclosed by the region `label` within `bounds`.
+    fn count_holes(
+        &self,
+        label: usize,
+        (min, max): (MatrixIdx, MatrixIdx),
+        connectivity: Connectivity,
+    ) -> usize {
+        // the bounds with a frame of outside cells, which connects everything not enclosed
+        let (height, width) = (max.row - min.row + 3, max.col - min.col + 3);
+        let mut inside = Matrix::from(vec![false; height * width], width);
+        for row in min.row..=max.row {
+            for col in min.col..=max.col {
+                inside[MatrixIdx::new(row - min.row + 1, col - min.col + 1)] =
+                    self[MatrixIdx::new(row, col)] == label;
+            }
+        }
+        let (areas, _) = inside.label(connectivity.dual(), |a, b| inside[a] == inside[b]);
+        let outside: HashSet<usize> = areas
+            .idx_value_iter()
+            .filter(|(idx, _)| !inside[*idx])
+            .map(|(_, &area)| area)
+            .collect();
+        // the frame is one area of outside cells
+        outside.len() - 1
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_components() {
+        let garden = Matrix::try_from_str_with("AAAA\nBBCD\nBBCC\nEEEC", |c| Some(*c)).unwrap();
+        let plots = garden.components(|a, b| a == b, Connectivity::Four);
+        assert_eq!(plots.regions.len(), 5);
+        let c = &plots.regions[plots.labels[MatrixIdx::new(1, 2)]];
+        assert_eq!(
+            c,
+            &Region {
+                area: 4,
+                perimeter: 10,
+                sides: 8,
+                bounds: (MatrixIdx::new(1, 2), MatrixIdx::new(3, 3)),
+            }
+        );
+        assert_eq!(plots.holes(plots.labels[MatrixIdx::new(1, 2)]), 0);
+        assert_eq!(plots.labels[MatrixIdx::new(0, 3)], 0);
+
+        // the X cells only touch diagonally
+        let grid = Matrix::try_from_str_with(".X.\nX.X\n.X.", |c| Some(*c)).unwrap();
+        let is_x = |a: &char, b: &char| a == b && *a == 'X';
+        assert_eq!(grid.components(is_x, Connectivity::Four).regions.len(), 9);
+        let diagonal = grid.components(is_x, Connectivity::Eight);
+        assert_eq!(diagonal.regions.len(), 6);
+        let label = diagonal.labels[MatrixIdx::new(0, 1)];
+        let ring = &diagonal.regions[label];
+        assert_eq!((ring.area, ring.perimeter), (4, 16));
+        assert_eq!(diagonal.holes(label), 1);
+    }
+}
@@ -0,0 +1,322 @@
+//! Shortest paths on a [`Grid`] and on any graph of search states.
+//!
+//! [`bfs`], [`dijkstra`] and [`astar`] step between the orthogonal neighbours of a grid,
+//! `passable` decides which cells can be entered and `cost` what a step costs. Puzzles whose next
+//! steps depend on more than the cell, like the direction a reindeer is facing, use the `*_by`
+//! variants, which get the successors of a state from a closure.
+//!
+//! ```
+//! use adventofcode2024::matrix::{search, Matrix, MatrixIdx};
+//!
+//! let maze = Matrix::try_from_str_with("..#\n#..\n...", |c| Some(*c)).unwrap();
+//! let (start, end) = (MatrixIdx::new(0, 0), MatrixIdx::new(2, 2));
+//! let found = search::bfs(&maze, start, |tile| *tile == '.', Some(end));
+//! assert_eq!(found.distance(&end), Some(4));
+//! assert_eq!(found.path(&end).unwrap().len(), 5);
+//! ```
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+use super::Grid;
+use crate::point::Point2;
+
+/// The outcome of a search: the distance and predecessor of every state reached.
+///
+/// A search stopping at its goal has settled every state up to the goal's distance. States
+/// further away may be missing or have a distance that is too large.
+#[derive(Debug, Clone)]
+pub struct Search<S> {
+    pub distances: HashMap<S, usize>,
+    /// The state each reached state was entered from, starts have none.
+    pub predecessors: HashMap<S, S>,
+    /// The goal the search stopped at, `None` if no goal was reached.
+    pub goal: Option<S>,
+}
+
+impl<S: Copy + Eq + Hash> Search<S> {
+    fn new() -> Self {
+        Self {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+            goal: None,
+        }
+    }
+    pub fn distance(&self, state: &S) -> Option<usize> {
+        self.distances.get(state).copied()
+    }
+    /// The states from a start to `target`, both included.
+    pub fn path(&self, target: &S) -> Option<Vec<S>> {
+        self.distances.get(target)?;
+        let mut path = vec![*target];
+        while let Some(prev) = self.predecessors.get(path.last()?) {
+            path.push(*prev);
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth first search from `starts`, where every step costs 1.
+pub fn bfs_by<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Copy + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new();
+    let mut front = VecDeque::new();
+    for start in starts {
+        if search.distances.insert(start, 0).is_none() {
+            front.push_back(start);
+        }
+    }
+    while let Some(state) = front.pop_front() {
+        if is_goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+        let distance = search.distances[&state] + 1;
+        for next in successors(&state) {
+            if let Entry::Vacant(entry) = search.distances.entry(next) {
+                entry.insert(distance);
+                search.predecessors.insert(next, state);
+                front.push_back(next);
+            }
+        }
+    }
+    search
+}
+
+/// Dijkstra's search from `starts`, `successors` yields the next states with the cost of getting
+/// there.
+pub fn dijkstra_by<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Copy + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    astar_by(starts, successors, |_| 0, is_goal)
+}
+
+/// A* search from `starts`, like [`dijkstra_by`] but visiting the states in the order of their
+/// distance plus `heuristic`.
+///
+/// The heuristic must never overestimate the remaining cost, and for a step from `a` to `b` it
+/// may drop by at most the cost of the step, otherwise the distances can be wrong.
+pub fn astar_by<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> usize,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Copy + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut search = Search::new();
+    // the heap refers to the states by their position in `states`, so they need not be `Ord`
+    let mut states = Vec::new();
+    let mut front = BinaryHeap::new();
+    for start in starts {
+        if search.distances.insert(start, 0).is_none() {
+            front.push(Reverse((heuristic(&start), 0, states.len())));
+            states.push(start);
+        }
+    }
+    while let Some(Reverse((_, distance, id))) = front.pop() {
+        let state = states[id];
+        if distance > search.distances[&state] {
+            // a shorter way to the state was found after this one was queued
+            continue;
+        }
+        if is_goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+        for (next, cost) in successors(&state) {
+            let next_distance = distance + cost;
+            if search
+                .distances
+                .get(&next)
+                .is_none_or(|&known| next_distance < known)
+            {
+                search.distances.insert(next, next_distance);
+                search.predecessors.insert(next, state);
+                front.push(Reverse((
+                    next_distance + heuristic(&next),
+                    next_distance,
+                    states.len(),
+                )));
+                states.push(next);
+            }
+        }
+    }
+    search
+}
+
+/// The orthogonal neighbours of `idx` that can be entered.
+fn passable_neighbors<'a, G: Grid>(
+    grid: &'a G,
+    idx: &G::Idx,
+    passable: &'a impl Fn(&G::Item) -> bool,
+) -> impl Iterator<Item = G::Idx> + 'a {
+    grid.neighbors4(idx)
+        .filter(|(_, _, value)| passable(value))
+        .map(|(_, next, _)| next)
+}
+
+/// Breadth first search through the cells of `grid` from `start` until `goal`, or through all
+/// reachable cells without one.
+///
+/// The start is entered even if it is not passable.
+pub fn bfs<G: Grid>(
+    grid: &G,
+    start: G::Idx,
+    passable: impl Fn(&G::Item) -> bool,
+    goal: Option<G::Idx>,
+) -> Search<G::Idx> {
+    bfs_by(
+        [start],
+        |idx| passable_neighbors(grid, idx, &passable),
+        |idx| Some(*idx) == goal,
+    )
+}
+
+/// Dijkstra's search through the cells of `grid`, where `cost` is the cost of the step between
+/// two neighbouring cells, see [`bfs`].
+pub fn dijkstra<G: Grid>(
+    grid: &G,
+    start: G::Idx,
+    passable: impl Fn(&G::Item) -> bool,
+    cost: impl Fn(G::Idx, G::Idx) -> usize,
+    goal: Option<G::Idx>,
+) -> Search<G::Idx> {
+    let cost = &cost;
+    dijkstra_by(
+        [start],
+        |idx| {
+            let from = *idx;
+            passable_neighbors(grid, idx, &passable).map(move |next| (next, cost(from, next)))
+        },
+        |idx| Some(*idx) == goal,
+    )
+}
+
+/// A* search from `start` to `goal` guided by the manhattan distance, so every step has to cost
+/// at least 1, see [`dijkstra`].
+pub fn astar<G>(
+    grid: &G,
+    start: G::Idx,
+    passable: impl Fn(&G::Item) -> bool,
+    cost: impl Fn(G::Idx, G::Idx) -> usize,
+    goal: G::Idx,
+) -> Search<G::Idx>
+where
+    G: Grid,
+    G::Idx: Into<Point2>,
+{
+    let cost = &cost;
+    let target: Point2 = goal.into();
+    astar_by(
+        [start],
+        |idx| {
+            let from = *idx;
+            passable_neighbors(grid, idx, &passable).map(move |next| (next, cost(from, next)))
+        },
+        |idx| (target - (*idx).into()).manhattan() as usize,
+        |idx| *idx == goal,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::matrix::{Matrix, MatrixIdx};
+    use crate::point::Vec2;
+
+    const MAZE: &str = "\
+S.....
+.####.
+.#..#.
+.#.##.
+...#.E";
+
+    /// A short route along the top and a long one around the wall.
+    const ROUTES: &str = "\
+S...E
+.###.
+.....";
+
+    #[test]
+    fn test_grid_search() {
+        let maze = Matrix::try_from_str_with(MAZE, |c| Some(*c)).unwrap();
+        let (start, end) = (MatrixIdx::new(0, 0), MatrixIdx::new(4, 5));
+        let passable = |tile: &char| *tile != '#';
+
+        let found = bfs(&maze, start, passable, Some(end));
+        assert_eq!(found.goal, Some(end));
+        assert_eq!(found.distance(&end), Some(9));
+        let path = found.path(&end).unwrap();
+        assert_eq!((path[0], path.len()), (start, 10));
+        assert!(path
+            .windows(2)
+            .all(|step| Vec2::from(step[1] - step[0]).manhattan() == 1));
+
+        let everything = bfs(&maze, start, passable, None);
+        assert_eq!(everything.goal, None);
+        assert_eq!(everything.distance(&MatrixIdx::new(2, 3)), Some(9));
+        assert_eq!(everything.distance(&MatrixIdx::new(4, 4)), Some(10));
+        assert_eq!(everything.distance(&MatrixIdx::new(1, 1)), None);
+
+        let cost = |_, _| 1;
+        assert!(astar(&maze, start, passable, cost, MatrixIdx::new(1, 1))
+            .goal
+            .is_none());
+    }
+
+    #[test]
+    fn test_costs() {
+        let maze = Matrix::try_from_str_with(ROUTES, |c| Some(*c)).unwrap();
+        let (start, end) = (MatrixIdx::new(0, 0), MatrixIdx::new(0, 4));
+        let passable = |tile: &char| *tile != '#';
+        assert_eq!(
+            bfs(&maze, start, passable, Some(end)).distance(&end),
+            Some(4)
+        );
+
+        // entering the middle of the top row is expensive, so the long route is cheaper
+        let cost = |_, to: MatrixIdx| if to.row == 0 && to.col < 4 { 10 } else { 1 };
+        let around = MatrixIdx::new(1, 0);
+        let cheapest = dijkstra(&maze, start, passable, cost, Some(end));
+        assert_eq!(cheapest.distance(&end), Some(8));
+        assert_eq!(cheapest.path(&end).unwrap()[1], around);
+        let guided = astar(&maze, start, passable, cost, end);
+        assert_eq!(guided.distance(&end), Some(8));
+        assert_eq!(guided.path(&end).unwrap()[1], around);
+
+        // with the top row cheap again the short route wins
+        let cheap = |_, _| 3;
+        let direct = astar(&maze, start, passable, cheap, end);
+        assert_eq!(direct.distance(&end), Some(12));
+        assert_eq!(direct.path(&end).unwrap()[1], MatrixIdx::new(0, 1));
+    }
+
+    #[test]
+    fn test_search_by() {
+        // counting up with +1 costing 1 and *2 costing 3
+        let successors = |n: &u32| [(n + 1, 1), (n * 2, 3)];
+        let found = dijkstra_by([1], successors, |n| *n == 20);
+        assert_eq!(found.distance(&20), Some(10));
+        assert_eq!(found.path(&20).unwrap(), vec![1, 2, 3, 4, 5, 10, 20]);
+        let steps = bfs_by([1], |n: &u32| [n + 1, n * 2], |n| *n == 20);
+        assert_eq!(steps.distance(&20), Some(5));
+    }
+}
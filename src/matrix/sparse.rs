@@ -0,0 +1,254 @@
+//! Grids without fixed size, whose cells can have negative coordinates.
+use std::collections::hash_map::{self, HashMap};
+use std::ops::Index;
+
+use super::{Grid, Matrix, MatrixElement, MatrixIdx, MatrixIdxOffset, ALL_DIRECTIONS};
+use crate::point::{Point2, Vec2};
+
+/// The cells of an unbounded plane that hold a value, e.g. the few walls of a huge maze or
+/// robots walking anywhere.
+///
+/// As a [`Grid`] only the cells holding a value can be entered. Searches through the empty
+/// cells, like the free space of a maze, step with [`SparseGrid::passable_neighbors`].
+///
+/// The bounds grow with every cell inserted, so the grid can be shown as a dense [`Matrix`]:
+///
+/// ```
+/// use adventofcode2024::matrix::SparseGrid;
+/// use adventofcode2024::point::Point2;
+///
+/// let mut grid = SparseGrid::new();
+/// grid.insert(Point2::new(-1, 0), '#');
+/// grid.insert(Point2::new(1, 1), '@');
+/// assert_eq!(grid.bounds(), Some((Point2::new(-1, 0), Point2::new(1, 1))));
+/// assert_eq!(grid.to_matrix().unwrap().to_string(), "#..\n..@");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point2, T>,
+    /// The smallest and the largest coordinates of all cells ever inserted.
+    bounds: Option<(Point2, Point2)>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+    /// Sets the value at `point`, returning the value it replaced.
+    pub fn insert(&mut self, point: Point2, value: T) -> Option<T> {
+        self.grow(point);
+        self.cells.insert(point, value)
+    }
+    /// Empties the cell at `point`, the bounds keep including it.
+    pub fn remove(&mut self, point: &Point2) -> Option<T> {
+        self.cells.remove(point)
+    }
+    /// The cell at `point` for updating it in place, the bounds grow to include it.
+    pub fn entry(&mut self, point: Point2) -> hash_map::Entry<'_, Point2, T> {
+        self.grow(point);
+        self.cells.entry(point)
+    }
+    fn grow(&mut self, point: Point2) {
+        let (min, max) = self.bounds.get_or_insert((point, point));
+        *min = Point2::new(min.x.min(point.x), min.y.min(point.y));
+        *max = Point2::new(max.x.max(point.x), max.y.max(point.y));
+    }
+    pub fn get(&self, point: &Point2) -> Option<&T> {
+        self.cells.get(point)
+    }
+    pub fn get_mut(&mut self, point: &Point2) -> Option<&mut T> {
+        self.cells.get_mut(point)
+    }
+    pub fn contains(&self, point: &Point2) -> bool {
+        self.cells.contains_key(point)
+    }
+    /// The number of cells holding a value.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+    /// The cells holding a value, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point2, &T)> {
+        self.cells.iter().map(|(point, value)| (*point, value))
+    }
+    /// The top left and the bottom right corner of the smallest rectangle holding every cell
+    /// inserted so far, `None` for a new grid.
+    pub fn bounds(&self) -> Option<(Point2, Point2)> {
+        self.bounds
+    }
+    /// Copies the bounds into a matrix, whose top left cell is the top left corner of the
+    /// bounds. `None` for a new grid.
+    pub fn to_matrix(&self) -> Option<Matrix<Option<T>>>
+    where
+        T: MatrixElement,
+    {
+        let (min, max) = self.bounds?;
+        let (height, width) = ((max.y - min.y + 1) as usize, (max.x - min.x + 1) as usize);
+        Some(Matrix::from_fn(height, width, |idx| {
+            self.get(&(min + Vec2::new(idx.col as i64, idx.row as i64)))
+                .cloned()
+        }))
+    }
+    /// The orthogonal neighbours of `point` within the rectangle `limits`, empty or not, that
+    /// `passable` allows entering.
+    ///
+    /// ```
+    /// use adventofcode2024::matrix::{search, SparseGrid};
+    /// use adventofcode2024::point::Point2;
+    ///
+    /// let walls: SparseGrid<()> = [(1, 0), (1, 1)]
+    ///     .into_iter()
+    ///     .map(|(x, y)| (Point2::new(x, y), ()))
+    ///     .collect();
+    /// let limits = (Point2::new(0, 0), Point2::new(2, 2));
+    /// let (start, exit) = (Point2::new(0, 0), Point2::new(2, 0));
+    /// let free = |cell: Option<&()>| cell.is_none();
+    /// let found = search::bfs_by(
+    ///     [start],
+    ///     |point| walls.passable_neighbors(point, limits, free),
+    ///     |point| *point == exit,
+    /// );
+    /// assert_eq!(found.distance(&exit), Some(6));
+    /// ```
+    pub fn passable_neighbors<'a>(
+        &'a self,
+        point: &Point2,
+        (min, max): (Point2, Point2),
+        passable: impl Fn(Option<&T>) -> bool + 'a,
+    ) -> impl Iterator<Item = Point2> + 'a {
+        let point = *point;
+        ALL_DIRECTIONS
+            .into_iter()
+            .map(move |dir| point + Vec2::from(dir.offset()))
+            .filter(move |next| {
+                (min.x..=max.x).contains(&next.x)
+                    && (min.y..=max.y).contains(&next.y)
+                    && passable(self.get(next))
+            })
+    }
+    /// The index of `point` in [`SparseGrid::to_matrix`], `None` outside of the bounds.
+    pub fn matrix_idx(&self, point: &Point2) -> Option<MatrixIdx> {
+        let (min, max) = self.bounds?;
+        if point.x > max.x || point.y > max.y {
+            return None;
+        }
+        MatrixIdx::try_from(Point2::new(point.x - min.x, point.y - min.y)).ok()
+    }
+}
+
+impl<T> Grid for SparseGrid<T> {
+    type Idx = Point2;
+    type Item = T;
+    fn get(&self, point: &Point2) -> Option<&T> {
+        SparseGrid::get(self, point)
+    }
+    /// Never leaves the grid, which is unbounded.
+    fn offset_idx(&self, point: &Point2, offset: &MatrixIdxOffset) -> Option<Point2> {
+        Some(*point + Vec2::from(*offset))
+    }
+}
+
+impl<T> FromIterator<(Point2, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point2, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        for (point, value) in iter {
+            grid.insert(point, value);
+        }
+        grid
+    }
+}
+
+/// Copies the cells of a matrix holding a value, at the coordinates of their index.
+impl<T: MatrixElement> From<&Matrix<Option<T>>> for SparseGrid<T> {
+    fn from(matrix: &Matrix<Option<T>>) -> Self {
+        matrix
+            .idx_value_iter()
+            .filter_map(|(idx, value)| Some((Point2::from(idx), value.clone()?)))
+            .collect()
+    }
+}
+
+impl<T> Index<Point2> for SparseGrid<T> {
+    type Output = T;
+    fn index(&self, point: Point2) -> &Self::Output {
+        self.get(&point)
+            .unwrap_or_else(|| panic!("{point:?} is empty"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::matrix::search;
+
+    #[test]
+    fn test_sparse_grid() {
+        let mut grid: SparseGrid<char> = [(Point2::new(2, -3), 'a'), (Point2::new(-4, 1), 'b')]
+            .into_iter()
+            .collect();
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid[Point2::new(-4, 1)], 'b');
+        assert_eq!(grid.get(&Point2::new(0, 0)), None);
+        assert_eq!(
+            grid.bounds(),
+            Some((Point2::new(-4, -3), Point2::new(2, 1)))
+        );
+        *grid.entry(Point2::new(5, 0)).or_insert('c') = 'd';
+        assert!(grid.contains(&Point2::new(5, 0)));
+        assert_eq!(grid.remove(&Point2::new(5, 0)), Some('d'));
+        assert_eq!(
+            grid.bounds().unwrap().1,
+            Point2::new(5, 1),
+            "bounds do not shrink"
+        );
+
+        let dense = grid.to_matrix().unwrap();
+        assert_eq!((dense.height(), dense.width()), (5, 10));
+        let idx = grid.matrix_idx(&Point2::new(2, -3)).unwrap();
+        assert_eq!(dense[idx], Some('a'));
+        assert_eq!(grid.matrix_idx(&Point2::new(-5, 0)), None);
+        assert_eq!(SparseGrid::from(&dense).len(), 2);
+        assert!(SparseGrid::<char>::new().to_matrix().is_none());
+    }
+
+    #[test]
+    fn test_sparse_search() {
+        // a path of cells around the origin, which is empty
+        let path: SparseGrid<()> = [(-1, -1), (0, -1), (1, -1), (1, 0), (1, 1), (0, 1)]
+            .into_iter()
+            .map(|(x, y)| (Point2::new(x, y), ()))
+            .collect();
+        let start = Point2::new(-1, -1);
+        let neighbors: Vec<_> = path.neighbors4(&Point2::new(1, 0)).collect();
+        assert_eq!(neighbors.len(), 2);
+        assert_eq!(path.neighbors8(&Point2::new(0, 0)).count(), 6);
+
+        let found = search::bfs(&path, start, |_| true, Some(Point2::new(0, 1)));
+        assert_eq!(found.distance(&Point2::new(0, 1)), Some(5));
+        let everything = search::bfs(&path, start, |_| true, None);
+        assert_eq!(everything.distances.len(), 6);
+        let guided = search::astar(&path, start, |_| true, |_, _| 1, Point2::new(1, 1));
+        assert_eq!(guided.distance(&Point2::new(1, 1)), Some(4));
+
+        // the empty cells inside the bounds: the origin and the two on its left
+        let limits = path.bounds().unwrap();
+        let free = search::bfs_by(
+            [Point2::new(0, 0)],
+            |point| path.passable_neighbors(point, limits, |cell| cell.is_none()),
+            |_| false,
+        );
+        assert_eq!(free.distances.len(), 3);
+        assert_eq!(free.distance(&Point2::new(-1, 1)), Some(2));
+    }
+}
@@ -0,0 +1,56 @@
+//! Copies of a [`Matrix`] with its cells rearranged.
+use super::{Matrix, MatrixElement, MatrixIdx};
+
+impl<T: MatrixElement> Matrix<T> {
+    /// Mirrors the matrix at its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self {
+        Matrix::from_fn(self.width(), self.height(), |idx| {
+            self[MatrixIdx::new(idx.col, idx.row)].clone()
+        })
+    }
+    /// Turns the matrix by 90° clockwise, the first column becomes the first row.
+    pub fn rotate_cw(&self) -> Self {
+        let height = self.height();
+        Matrix::from_fn(self.width(), height, |idx| {
+            self[MatrixIdx::new(height - 1 - idx.col, idx.row)].clone()
+        })
+    }
+    /// Turns the matrix by 90° counterclockwise, the last column becomes the first row.
+    pub fn rotate_ccw(&self) -> Self {
+        let width = self.width();
+        Matrix::from_fn(width, self.height(), |idx| {
+            self[MatrixIdx::new(idx.col, width - 1 - idx.row)].clone()
+        })
+    }
+    /// Mirrors the matrix left to right.
+    pub fn flip_h(&self) -> Self {
+        let width = self.width();
+        Matrix::from_fn(self.height(), width, |idx| {
+            self[MatrixIdx::new(idx.row, width - 1 - idx.col)].clone()
+        })
+    }
+    /// Mirrors the matrix top to bottom.
+    pub fn flip_v(&self) -> Self {
+        let height = self.height();
+        Matrix::from_fn(height, self.width(), |idx| {
+            self[MatrixIdx::new(height - 1 - idx.row, idx.col)].clone()
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_transforms() {
+        let grid = Matrix::try_from_str_with("abc\ndef", |c| Some(*c)).unwrap();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.flip_h().to_string(), "cba\nfed");
+        assert_eq!(grid.flip_v().to_string(), "def\nabc");
+        assert_eq!(grid.rotate_cw().rotate_cw().to_string(), "fed\ncba");
+        assert_eq!(grid.rotate_cw().rotate_ccw().to_string(), grid.to_string());
+    }
+}
@@ -0,0 +1,294 @@
+//! Borrowed rectangles of a [`Matrix`] and the columns and diagonals of both.
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Display};
+use std::ops::Index;
+
+use super::{Grid, Matrix, MatrixElement, MatrixIdx, MatrixIdxOffset, Render, ToChar};
+
+/// A rectangle of a [`Matrix`] read like a matrix of its own, with indices relative to its top
+/// left corner.
+///
+/// ```
+/// use adventofcode2024::matrix::{Matrix, MatrixIdx};
+///
+/// let grid = Matrix::try_from_str_with("abcd\nefgh\nijkl", |c| Some(*c)).unwrap();
+/// let view = grid.view(MatrixIdx::new(1, 1), 2, 3).unwrap();
+/// assert_eq!(view[MatrixIdx::new(0, 0)], 'f');
+/// assert_eq!(view.to_string(), "fgh\njkl");
+/// assert!(grid.view(MatrixIdx::new(1, 2), 2, 3).is_none());
+/// ```
+#[derive(Debug)]
+pub struct MatrixView<'a, T: MatrixElement> {
+    matrix: &'a Matrix<T>,
+    origin: MatrixIdx,
+    height: usize,
+    width: usize,
+}
+
+impl<T: MatrixElement> Clone for MatrixView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T: MatrixElement> Copy for MatrixView<'_, T> {}
+
+impl<'a, T: MatrixElement> Matrix<T> {
+    /// The `height` × `width` rectangle starting at `top_left`, `None` if it is empty or does
+    /// not fit.
+    pub fn view(
+        &'a self,
+        top_left: MatrixIdx,
+        height: usize,
+        width: usize,
+    ) -> Option<MatrixView<'a, T>> {
+        self.as_view().view(top_left, height, width)
+    }
+    /// The whole matrix as a [`MatrixView`].
+    pub fn as_view(&'a self) -> MatrixView<'a, T> {
+        MatrixView {
+            matrix: self,
+            origin: MatrixIdx::new(0, 0),
+            height: self.height(),
+            width: self.width(),
+        }
+    }
+    /// The columns from left to right, each from top to bottom.
+    pub fn cols(&'a self) -> impl Iterator<Item = impl Iterator<Item = &'a T> + 'a> + 'a {
+        self.as_view().cols()
+    }
+    /// The diagonals running down to the right, see [`MatrixView::diagonals`].
+    pub fn diagonals(&'a self) -> impl Iterator<Item = impl Iterator<Item = &'a T> + 'a> + 'a {
+        self.as_view().diagonals()
+    }
+    /// The diagonals running down to the left, see [`MatrixView::anti_diagonals`].
+    pub fn anti_diagonals(&'a self) -> impl Iterator<Item = impl Iterator<Item = &'a T> + 'a> + 'a {
+        self.as_view().anti_diagonals()
+    }
+}
+
+impl<'a, T: MatrixElement> MatrixView<'a, T> {
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    /// The index in the underlying matrix of `idx` in the view.
+    pub fn matrix_idx(&self, idx: &MatrixIdx) -> MatrixIdx {
+        MatrixIdx::new(self.origin.row + idx.row, self.origin.col + idx.col)
+    }
+    pub fn is_valid_idx(&self, idx: &MatrixIdx) -> bool {
+        idx.row < self.height && idx.col < self.width
+    }
+    pub fn get(&self, idx: &MatrixIdx) -> Option<&'a T> {
+        self.is_valid_idx(idx).then(|| self.at(idx))
+    }
+    /// The value at `idx`, which has to be inside of the view.
+    fn at(&self, idx: &MatrixIdx) -> &'a T {
+        &self.matrix[self.matrix_idx(idx)]
+    }
+    /// The `height` × `width` rectangle of this view starting at `top_left`, `None` if it is
+    /// empty or does not fit.
+    pub fn view(&self, top_left: MatrixIdx, height: usize, width: usize) -> Option<Self> {
+        // an empty view has no rows to derive its size from and nothing to clamp or wrap into
+        let fits = |start: usize, len: usize, size: usize| {
+            len > 0 && start.checked_add(len).is_some_and(|end| end <= size)
+        };
+        (fits(top_left.row, height, self.height) && fits(top_left.col, width, self.width)).then(
+            || MatrixView {
+                matrix: self.matrix,
+                origin: self.matrix_idx(&top_left),
+                height,
+                width,
+            },
+        )
+    }
+    /// Returns the indizes of the view, row by row.
+    pub fn indizes(&self) -> impl Iterator<Item = MatrixIdx> {
+        let width = self.width;
+        (0..self.height * width).map(move |linidx| MatrixIdx::new(linidx / width, linidx % width))
+    }
+    /// The indices and values of the view, row by row.
+    pub fn idx_value_iter(&self) -> impl Iterator<Item = (MatrixIdx, &'a T)> + 'a {
+        self.rows().enumerate().flat_map(|(row, values)| {
+            values
+                .iter()
+                .enumerate()
+                .map(move |(col, value)| (MatrixIdx::new(row, col), value))
+        })
+    }
+    pub fn find(&self, value: &T) -> Option<MatrixIdx> {
+        self.idx_value_iter()
+            .find(|(_, val)| *val == value)
+            .map(|(idx, _)| idx)
+    }
+    pub fn find_all(&self, value: &T) -> Vec<MatrixIdx> {
+        self.idx_value_iter()
+            .filter(|(_, val)| *val == value)
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + 'a {
+        let view = *self;
+        (0..self.height).map(move |row| {
+            let start = view
+                .matrix
+                .linidx(&view.matrix_idx(&MatrixIdx::new(row, 0)));
+            &view.matrix.data[start..start + view.width]
+        })
+    }
+    /// The columns from left to right, each from top to bottom.
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = &'a T> + 'a> + 'a {
+        let view = *self;
+        (0..self.width)
+            .map(move |col| (0..view.height).map(move |row| view.at(&MatrixIdx::new(row, col))))
+    }
+    /// The diagonals running down to the right, starting with the bottom left corner and ending
+    /// with the top right one.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &'a T> + 'a> + 'a {
+        let view = *self;
+        let (height, width) = (self.height, self.width);
+        (0..self.diagonal_count()).map(move |d| {
+            let start = if d < height {
+                MatrixIdx::new(height - 1 - d, 0)
+            } else {
+                MatrixIdx::new(0, d + 1 - height)
+            };
+            let len = (height - start.row).min(width - start.col);
+            (0..len).map(move |i| view.at(&MatrixIdx::new(start.row + i, start.col + i)))
+        })
+    }
+    /// The diagonals running down to the left, starting with the top left corner and ending with
+    /// the bottom right one.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &'a T> + 'a> + 'a {
+        let view = *self;
+        let (height, width) = (self.height, self.width);
+        (0..self.diagonal_count()).map(move |d| {
+            let start = if d < width {
+                MatrixIdx::new(0, d)
+            } else {
+                MatrixIdx::new(d + 1 - width, width - 1)
+            };
+            let len = (height - start.row).min(start.col + 1);
+            (0..len).map(move |i| view.at(&MatrixIdx::new(start.row + i, start.col - i)))
+        })
+    }
+    fn diagonal_count(&self) -> usize {
+        if self.height == 0 || self.width == 0 {
+            0
+        } else {
+            self.height + self.width - 1
+        }
+    }
+    /// `idx` moved by `offset`, `None` if that leaves the view.
+    pub fn offset_idx(&self, idx: &MatrixIdx, offset: &MatrixIdxOffset) -> Option<MatrixIdx> {
+        idx.checked_add(offset)
+            .filter(|next| self.is_valid_idx(next))
+    }
+    /// Copies the cells of the view into a matrix of their own.
+    pub fn to_matrix(&self) -> Matrix<T> {
+        Matrix::from_fn(self.height, self.width, |idx| self[idx].clone())
+    }
+}
+
+impl<T: MatrixElement> Grid for MatrixView<'_, T> {
+    type Idx = MatrixIdx;
+    type Item = T;
+    fn get(&self, idx: &MatrixIdx) -> Option<&T> {
+        MatrixView::get(self, idx)
+    }
+    fn offset_idx(&self, idx: &MatrixIdx, offset: &MatrixIdxOffset) -> Option<MatrixIdx> {
+        MatrixView::offset_idx(self, idx, offset)
+    }
+}
+
+impl<T: MatrixElement> Index<MatrixIdx> for MatrixView<'_, T> {
+    type Output = T;
+    fn index(&self, index: MatrixIdx) -> &Self::Output {
+        self.get(&index).unwrap_or_else(|| {
+            panic!(
+                "{index:?} is outside of the {}x{} view",
+                self.height, self.width
+            )
+        })
+    }
+}
+impl<T: MatrixElement> Index<&MatrixIdx> for MatrixView<'_, T> {
+    type Output = T;
+    fn index(&self, index: &MatrixIdx) -> &Self::Output {
+        &self[*index]
+    }
+}
+
+impl<'a, T: MatrixElement> MatrixView<'a, T> {
+    /// Prepares printing the view with markers drawn over some cells, see [`Render`].
+    pub fn render(&self) -> Render<'a, T> {
+        Render {
+            view: *self,
+            marks: HashMap::new(),
+            highlights: HashSet::new(),
+        }
+    }
+}
+
+/// Prints one line per row like [`Matrix`].
+impl<T: MatrixElement + ToChar> Display for MatrixView<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.render().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn collect<'a>(lines: impl Iterator<Item = impl Iterator<Item = &'a char>>) -> Vec<String> {
+        lines.map(|line| line.collect()).collect()
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = Matrix::try_from_str_with("abcd\nefgh\nijkl", |c| Some(*c)).unwrap();
+        assert_eq!(collect(grid.cols()), ["aei", "bfj", "cgk", "dhl"]);
+        assert_eq!(
+            collect(grid.diagonals()),
+            ["i", "ej", "afk", "bgl", "ch", "d"]
+        );
+        assert_eq!(
+            collect(grid.anti_diagonals()),
+            ["a", "be", "cfi", "dgj", "hk", "l"]
+        );
+        let view = grid.view(MatrixIdx::new(0, 1), 3, 2).unwrap();
+        assert_eq!(collect(view.diagonals()), ["j", "fk", "bg", "c"]);
+        assert_eq!(collect(view.anti_diagonals()), ["b", "cf", "gj", "k"]);
+    }
+
+    #[test]
+    fn test_view() {
+        let grid = Matrix::try_from_str_with("abcd\nefgh\nijkl", |c| Some(*c)).unwrap();
+        let view = grid.view(MatrixIdx::new(1, 1), 2, 3).unwrap();
+        assert_eq!((view.height(), view.width()), (2, 3));
+        assert_eq!(
+            view.rows().collect::<Vec<_>>(),
+            [&['f', 'g', 'h'], &['j', 'k', 'l']]
+        );
+        assert_eq!(view.get(&MatrixIdx::new(2, 0)), None);
+        assert_eq!(view.find(&'k'), Some(MatrixIdx::new(1, 1)));
+        assert_eq!(view.matrix_idx(&MatrixIdx::new(1, 1)), MatrixIdx::new(2, 2));
+        assert_eq!(view.find(&'a'), None, "outside of the view");
+        let neighbors: Vec<_> = view
+            .neighbors4(&MatrixIdx::new(0, 0))
+            .map(|(_, _, c)| *c)
+            .collect();
+        assert_eq!(neighbors, ['g', 'j']);
+        assert_eq!(view.neighbors8(&MatrixIdx::new(0, 1)).count(), 5);
+
+        let inner = view.view(MatrixIdx::new(0, 1), 2, 2).unwrap();
+        assert_eq!(inner.to_matrix().to_string(), "gh\nkl");
+        assert!(view.view(MatrixIdx::new(1, 0), 2, 1).is_none());
+        assert!(view.view(MatrixIdx::new(1, 0), usize::MAX, 1).is_none());
+        assert!(view.view(MatrixIdx::new(0, 0), 0, 2).is_none());
+        assert!(grid.view(MatrixIdx::new(1, 1), 2, 0).is_none());
+        assert_eq!(grid.as_view().to_string(), grid.to_string());
+    }
+}
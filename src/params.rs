@@ -0,0 +1,150 @@
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+
+/// Name of the file holding parameter overrides, stored next to the inputs of a day.
+pub const PARAMS_FILE: &str = "params.txt";
+
+/// A parameter of a puzzle and its value for the real puzzle input.
+pub type Param = (&'static str, &'static str);
+
+/// Values of the parameters of a day, e.g. the grid size or number of steps.
+///
+/// Starting from the day's defaults, values are overridden by the input's entry in
+/// [`PARAMS_FILE`] and then by the command line.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Params {
+    values: Vec<(String, String)>,
+}
+
+impl Params {
+    pub fn new(defaults: &[Param]) -> Self {
+        Self {
+            values: defaults
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+        }
+    }
+    /// Overrides the given parameters, which have to be known to the day.
+    pub fn apply(&mut self, overrides: &[(String, String)]) -> Result<()> {
+        for (name, value) in overrides {
+            let Some(entry) = self.values.iter_mut().find(|(known, _)| known == name) else {
+                return Err(Error::InvalidParam {
+                    name: name.clone(),
+                    msg: "unknown parameter".to_string(),
+                });
+            };
+            entry.1 = value.clone();
+        }
+        Ok(())
+    }
+    /// The names and values of all parameters, in the order of the day's defaults.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.values
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+    pub fn get<T: FromStr>(&self, name: &str) -> Result<T> {
+        let invalid = |msg: String| Error::InvalidParam {
+            name: name.to_string(),
+            msg,
+        };
+        let (_, value) = self
+            .values
+            .iter()
+            .find(|(known, _)| known == name)
+            .ok_or_else(|| invalid("unknown parameter".to_string()))?;
+        value
+            .parse()
+            .map_err(|_| invalid(format!("invalid value '{value}'")))
+    }
+}
+
+/// Parses a `name=value` assignment.
+pub fn parse_assignment(assignment: &str) -> Option<(String, String)> {
+    let (name, value) = assignment.split_once('=')?;
+    (!name.is_empty()).then(|| (name.to_string(), value.to_string()))
+}
+
+/// Parameter overrides of the inputs of a day, read from a `params.txt` like
+///
+/// ```text
+/// # input           parameters
+/// test_input1.txt   rows=7 cols=11
+/// ```
+///
+/// where `#` starts a comment. Inputs without an entry use the defaults of the day.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParamsFile {
+    pub entries: Vec<(String, Vec<(String, String)>)>,
+}
+
+impl ParamsFile {
+    pub fn parse(content: &str) -> Result<Self> {
+        let mut entries = Vec::new();
+        for (row, line) in content.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            let mut fields = line.split_whitespace();
+            let Some(input) = fields.next() else {
+                continue;
+            };
+            let overrides = fields
+                .map(|field| {
+                    parse_assignment(field).ok_or_else(|| {
+                        Error::malformed(format!(
+                            "{PARAMS_FILE} line {}: expected 'name=value', found '{field}'",
+                            row + 1
+                        ))
+                    })
+                })
+                .collect::<Result<_>>()?;
+            entries.push((input.to_string(), overrides));
+        }
+        Ok(Self { entries })
+    }
+    /// Loads the overrides stored in `dir`, a missing file overrides nothing.
+    pub fn load(dir: &Path) -> Result<Self> {
+        let path = dir.join(PARAMS_FILE);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        Self::parse(&crate::util::read_input(&path)?)
+    }
+    pub fn for_input(&self, input: &str) -> &[(String, String)] {
+        self.entries
+            .iter()
+            .find(|(name, _)| name == input)
+            .map_or(&[], |(_, overrides)| overrides)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_params() {
+        let file = ParamsFile::parse(
+            "# input parameters
+test_input1.txt rows=7 cols=11 # the example is smaller
+input2.txt
+",
+        )
+        .unwrap();
+        let mut params = Params::new(&[("rows", "103"), ("cols", "101")]);
+        assert_eq!(params.get::<usize>("rows").unwrap(), 103);
+        params.apply(file.for_input("input1.txt")).unwrap();
+        assert_eq!(params.get::<usize>("cols").unwrap(), 101);
+        params.apply(file.for_input("test_input1.txt")).unwrap();
+        assert_eq!(params.get::<usize>("rows").unwrap(), 7);
+        assert_eq!(params.get::<usize>("cols").unwrap(), 11);
+
+        assert!(params.get::<usize>("steps").is_err());
+        assert!(params.apply(&[("steps".into(), "3".into())]).is_err());
+        params.apply(&[("rows".into(), "many".into())]).unwrap();
+        assert!(params.get::<usize>("rows").is_err());
+        assert!(ParamsFile::parse("input1.txt rows").is_err());
+    }
+}
@@ -0,0 +1,371 @@
+//! Parser combinators for the usual shapes of puzzle inputs.
+//!
+//! A parser is any function from a [`Located`] piece of the input to a value, the combinators
+//! split the input and hand the pieces on to other parsers. A day's input format then reads like
+//!
+//! ```
+//! use adventofcode2024::parser::{int, lines, list, pair, parse};
+//!
+//! let equations: Vec<(u64, Vec<u64>)> =
+//!     parse("190: 10 19\n3267: 81 40 27\n", lines(pair(": ", int, list(" ", int)))).unwrap();
+//! assert_eq!(equations[1], (3267, vec![81, 40, 27]));
+//! ```
+//!
+//! Input that does not match fails with an [`Error::Syntax`] pointing at the offending line and
+//! column, nothing is skipped silently.
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+pub use regex::{Captures, Regex};
+
+use crate::error::{Error, Result};
+
+/// A piece of the input together with the line and column it starts at, both 1-based.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Located<'a> {
+    pub text: &'a str,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl<'a> Located<'a> {
+    /// The whole input, starting at line 1, column 1.
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            line: 1,
+            column: 1,
+        }
+    }
+    /// The piece `text[start..end]`, located within the whole input.
+    ///
+    /// Only the text before `start` is scanned, so splitting off pieces from the front keeps
+    /// parsing linear.
+    fn slice(&self, start: usize, end: usize) -> Self {
+        let skipped = &self.text[..start];
+        let (line, column) = match skipped.rfind('\n') {
+            Some(newline) => (
+                self.line + skipped.matches('\n').count(),
+                skipped[newline + 1..].chars().count() + 1,
+            ),
+            None => (self.line, self.column + skipped.chars().count()),
+        };
+        Self {
+            text: &self.text[start..end],
+            line,
+            column,
+        }
+    }
+    fn split_once(&self, separator: &str) -> Option<(Self, Self)> {
+        let start = self.text.find(separator)?;
+        Some((
+            self.slice(0, start),
+            self.slice(start + separator.len(), self.text.len()),
+        ))
+    }
+    fn split(&self, separator: &'a str) -> impl Iterator<Item = Self> + 'a {
+        // the rest after the pieces so far, located by scanning each piece once
+        let mut rest = *self;
+        self.text.split(separator).map(move |piece| {
+            let located = rest.slice(0, piece.len());
+            let next = (piece.len() + separator.len()).min(rest.text.len());
+            rest = rest.slice(next, rest.text.len());
+            located
+        })
+    }
+    /// Removes a single trailing newline, so the last line of a file does not count as empty.
+    fn without_final_newline(&self) -> Self {
+        let text = self.text.strip_suffix('\n').unwrap_or(self.text);
+        self.slice(0, text.len())
+    }
+    /// Removes a trailing carriage return of a line ending in `\r\n`.
+    fn without_carriage_return(&self) -> Self {
+        let text = self.text.strip_suffix('\r').unwrap_or(self.text);
+        self.slice(0, text.len())
+    }
+    /// An error stating what was expected at the start of this piece.
+    pub fn error(&self, expected: impl Into<String>) -> Error {
+        let found = self.text.lines().next().unwrap_or_default();
+        Error::Syntax {
+            line: self.line,
+            column: self.column,
+            expected: expected.into(),
+            found: found.chars().take(20).collect(),
+        }
+    }
+}
+
+/// Runs `parser` on the whole `content`.
+pub fn parse<'a, T>(content: &'a str, parser: impl Fn(Located<'a>) -> Result<T>) -> Result<T> {
+    parser(Located::new(content))
+}
+
+/// A (possibly signed) integer taking up the whole piece.
+pub fn int<T>(input: Located) -> Result<T>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    input.text.parse().map_err(|_| input.error("a number"))
+}
+
+/// The text of the piece, which must not be empty.
+pub fn text<'a>(input: Located<'a>) -> Result<&'a str> {
+    if input.text.is_empty() {
+        return Err(input.error("some text"));
+    }
+    Ok(input.text)
+}
+
+/// Two pieces separated by the first `separator`, like `47|53` or `190: 10 19`.
+pub fn pair<'a, A, B>(
+    separator: &'a str,
+    first: impl Fn(Located<'a>) -> Result<A>,
+    second: impl Fn(Located<'a>) -> Result<B>,
+) -> impl Fn(Located<'a>) -> Result<(A, B)> {
+    move |input| {
+        let (a, b) = input
+            .split_once(separator)
+            .ok_or_else(|| input.error(format!("two parts separated by {separator:?}")))?;
+        Ok((first(a)?, second(b)?))
+    }
+}
+
+/// Items separated by `separator`, like `75,47,61`.
+pub fn list<'a, T>(
+    separator: &'a str,
+    item: impl Fn(Located<'a>) -> Result<T>,
+) -> impl Fn(Located<'a>) -> Result<Vec<T>> {
+    move |input| input.split(separator).map(&item).collect()
+}
+
+/// One item per line, a final newline does not start another item and `\r\n` ends a line like
+/// `\n`.
+pub fn lines<'a, T>(
+    item: impl Fn(Located<'a>) -> Result<T>,
+) -> impl Fn(Located<'a>) -> Result<Vec<T>> {
+    move |input| {
+        let input = input.without_final_newline();
+        if input.text.is_empty() {
+            return Ok(Vec::new());
+        }
+        input
+            .split("\n")
+            .map(|line| item(line.without_carriage_return()))
+            .collect()
+    }
+}
+
+/// Two sections separated by an empty line, like the rules and the updates of day 5.
+pub fn sections<'a, A, B>(
+    first: impl Fn(Located<'a>) -> Result<A>,
+    second: impl Fn(Located<'a>) -> Result<B>,
+) -> impl Fn(Located<'a>) -> Result<(A, B)> {
+    move |input| {
+        let (a, b) = input
+            .split_once("\n\n")
+            .ok_or_else(|| input.error("two sections separated by an empty line"))?;
+        Ok((first(a)?, second(b)?))
+    }
+}
+
+/// Any number of blocks separated by empty lines, like the claw machines of day 13.
+pub fn blocks<'a, T>(
+    item: impl Fn(Located<'a>) -> Result<T>,
+) -> impl Fn(Located<'a>) -> Result<Vec<T>> {
+    list("\n\n", item)
+}
+
+/// A value labelled with `key`, like `Register A: 729`.
+pub fn key_value<'a, T>(
+    key: &'a str,
+    value: impl Fn(Located<'a>) -> Result<T>,
+) -> impl Fn(Located<'a>) -> Result<T> {
+    move |input| {
+        let (_, rest) = input
+            .split_once(": ")
+            .filter(|(label, _)| label.text == key)
+            .ok_or_else(|| input.error(format!("'{key}: '")))?;
+        value(rest)
+    }
+}
+
+/// Derives [`FromRegex`] for a struct whose fields are the named captures of its regex.
+///
+/// ```
+/// use adventofcode2024::parser::FromRegex;
+///
+/// #[derive(Debug, PartialEq, FromRegex)]
+/// #[regex(r"p=(?<x>\d+),(?<y>\d+) v=(?<dx>-?\d+),(?<dy>-?\d+)")]
+/// struct Robot {
+///     x: usize,
+///     y: usize,
+///     dx: i64,
+///     dy: i64,
+/// }
+///
+/// let robots = Robot::parse_all("p=0,4 v=3,-3\np=6,3 v=-1,-3\n").unwrap();
+/// assert_eq!(robots[1], Robot { x: 6, y: 3, dx: -1, dy: -3 });
+/// ```
+pub use adventofcode2024_derive::FromRegex;
+
+/// A record of the input described by a regex, usually derived with `#[derive(FromRegex)]`.
+pub trait FromRegex: Sized {
+    fn regex() -> &'static Regex;
+    /// Converts the captures of one match, describing the problem if a field is invalid.
+    fn from_captures(captures: &Captures) -> std::result::Result<Self, String>;
+
+    /// Parses every record of `content`, where only whitespace may separate the records.
+    ///
+    /// Fails with the number and line of the first record that is invalid or does not match.
+    fn parse_all(content: &str) -> Result<Vec<Self>> {
+        let invalid = |record: usize, offset: usize, msg: String| Error::InvalidRecord {
+            record,
+            line: content[..offset].matches('\n').count() + 1,
+            msg,
+        };
+        let unmatched = |record: usize, start: usize, end: usize| {
+            let skipped = &content[start..end];
+            let text = skipped.trim_start();
+            (!text.is_empty()).then(|| {
+                let first_line = text.lines().next().unwrap_or_default();
+                let msg = format!("'{first_line}' does not match {}", Self::regex());
+                invalid(record, start + skipped.len() - text.len(), msg)
+            })
+        };
+        let mut records = Vec::new();
+        let mut end = 0;
+        for captures in Self::regex().captures_iter(content) {
+            let found = captures.get(0).expect("every match has a whole capture");
+            let record = records.len() + 1;
+            if let Some(err) = unmatched(record, end, found.start()) {
+                return Err(err);
+            }
+            let value = Self::from_captures(&captures)
+                .map_err(|msg| invalid(record, found.start(), msg))?;
+            records.push(value);
+            end = found.end();
+        }
+        match unmatched(records.len() + 1, end, content.len()) {
+            Some(err) => Err(err),
+            None => Ok(records),
+        }
+    }
+    /// Parses `content` as exactly one record.
+    fn parse_one(content: &str) -> Result<Self> {
+        let mut records = Self::parse_all(content)?;
+        match records.len() {
+            1 => Ok(records.remove(0)),
+            n => Err(Error::InvalidRecord {
+                record: 1,
+                line: 1,
+                msg: format!("expected a single record, found {n}"),
+            }),
+        }
+    }
+}
+
+/// Parses the named capture `name`, used by the code `#[derive(FromRegex)]` generates.
+pub fn capture<T: FromStr>(captures: &Captures, name: &str) -> std::result::Result<T, String> {
+    let value = captures.name(name).map_or("", |value| value.as_str());
+    value
+        .parse()
+        .map_err(|_| format!("invalid value '{value}' for {name}"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn position(err: Error) -> (usize, usize) {
+        match err {
+            Error::Syntax { line, column, .. } => (line, column),
+            other => panic!("expected a syntax error, got {other}"),
+        }
+    }
+
+    #[test]
+    fn test_combinators() {
+        let updates = sections(lines(pair("|", int, int)), lines(list(",", int)));
+        let content = "47|53\n97|13\n\n75,47,61\n97,-3\n";
+        let (rules, pages): (Vec<(i32, i32)>, Vec<Vec<i32>>) = parse(content, &updates).unwrap();
+        assert_eq!(rules, vec![(47, 53), (97, 13)]);
+        assert_eq!(pages, vec![vec![75, 47, 61], vec![97, -3]]);
+
+        let register = key_value("Register A", int::<i64>);
+        assert_eq!(parse("Register A: 729", &register).unwrap(), 729);
+        assert_eq!(
+            parse("Program: 0,1", key_value("Program", list(",", int::<u8>))).unwrap(),
+            vec![0, 1]
+        );
+        assert_eq!(
+            parse("a\n\nb\n\nc", blocks(text)).unwrap(),
+            vec!["a", "b", "c"]
+        );
+        assert!(parse("", lines(text)).unwrap().is_empty());
+    }
+
+    #[derive(Debug, PartialEq, FromRegex)]
+    #[regex(r"Button (?<button>[AB]): X\+(?<x>\d+), Y\+(?<y>\d+)")]
+    struct Button {
+        button: char,
+        x: u8,
+        y: u8,
+    }
+
+    #[test]
+    fn test_from_regex() {
+        let buttons = Button::parse_all("Button A: X+94, Y+34\nButton B: X+22, Y+67\n").unwrap();
+        assert_eq!(
+            buttons[1],
+            Button {
+                button: 'B',
+                x: 22,
+                y: 67
+            }
+        );
+        assert_eq!(Button::parse_all("\n").unwrap(), vec![]);
+
+        let err = Button::parse_all("Button A: X+94, Y+34\nButton B: X+300, Y+67").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "record 2 at line 2: invalid value '300' for x"
+        );
+        let err = Button::parse_all("Button A: X+94, Y+34\n\nButton C: X+1, Y+1\n").unwrap_err();
+        assert!(matches!(
+            err,
+            Error::InvalidRecord {
+                record: 2,
+                line: 3,
+                ..
+            }
+        ));
+        assert!(Button::parse_one("Button A: X+94, Y+34").is_ok());
+        assert!(Button::parse_one("Button A: X+94, Y+34\nButton B: X+2, Y+6").is_err());
+    }
+
+    #[test]
+    fn test_positions() {
+        let updates = sections(
+            lines(pair("|", int::<i32>, int::<i32>)),
+            lines(list(",", int::<i32>)),
+        );
+        let err = parse("47|53\n97|13\n\n75,47,61\n97,x3\n", &updates).unwrap_err();
+        assert_eq!(position(err), (5, 4));
+        let err = parse("47|53\n9713\n\n75\n", &updates).unwrap_err();
+        assert_eq!(position(err), (2, 1));
+        let err = parse("47|53\n", &updates).unwrap_err();
+        assert_eq!(position(err), (1, 1));
+        let err = parse("Register B: 1", key_value("Register A", int::<i64>)).unwrap_err();
+        assert_eq!(position(err), (1, 1));
+        let err = parse("1 2  3", list(" ", int::<i64>)).unwrap_err();
+        assert_eq!(position(err), (1, 5));
+
+        let content = "1,2\r\n3,4\r\n".repeat(10_000) + "5,x\r\n";
+        let err = parse(&content, lines(list(",", int::<i32>))).unwrap_err();
+        assert_eq!(position(err), (20_001, 3));
+        assert_eq!(
+            parse("1,2\r\n3,4\r\n", lines(list(",", int::<i32>))).unwrap(),
+            vec![vec![1, 2], vec![3, 4]]
+        );
+    }
+}
@@ -0,0 +1,296 @@
+//! Points and vectors on the integer plane for puzzles whose coordinates can leave the grid or
+//! become negative.
+//!
+//! `x` grows to the right and `y` downwards, so they match the column and row of a
+//! [`MatrixIdx`] and converting between them never swaps the axes.
+use std::num::TryFromIntError;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+use crate::matrix::{MatrixIdx, MatrixIdxOffset};
+
+/// A position on the plane.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T = i64> {
+    pub x: T,
+    pub y: T,
+}
+
+/// The difference between two [`Point2`], e.g. a step or a velocity.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vec2<T = i64> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> Vec2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> Vec2<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    pub fn dot(&self, other: &Self) -> T {
+        self.x * other.x + self.y * other.y
+    }
+    /// The z component of the cross product, positive if `other` points clockwise of `self` as
+    /// shown with `y` downwards.
+    pub fn cross(&self, other: &Self) -> T {
+        self.x * other.y - self.y * other.x
+    }
+}
+
+impl<T> Vec2<T>
+where
+    T: Copy + Ord + Add<Output = T> + Neg<Output = T>,
+{
+    /// Turns the vector by 90° clockwise as shown with `y` downwards, e.g. up to right.
+    pub fn rotate_right(&self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+    /// Turns the vector by 90° counterclockwise as shown with `y` downwards, e.g. up to left.
+    pub fn rotate_left(&self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+    /// The number of orthogonal steps the vector spans.
+    pub fn manhattan(&self) -> T {
+        abs(self.x) + abs(self.y)
+    }
+    /// The number of steps the vector spans when diagonal steps are allowed.
+    pub fn chebyshev(&self) -> T {
+        abs(self.x).max(abs(self.y))
+    }
+}
+
+fn abs<T: Copy + Ord + Neg<Output = T>>(value: T) -> T {
+    value.max(-value)
+}
+
+impl Point2 {
+    /// The point brought back onto a grid of `(rows, cols)` whose opposite edges are connected.
+    pub fn wrapped_in(&self, (rows, cols): (usize, usize)) -> Self {
+        Self::new(
+            self.x.rem_euclid(cols as i64),
+            self.y.rem_euclid(rows as i64),
+        )
+    }
+}
+
+impl Vec2 {
+    /// The shortest vector pointing the same way whose components are integers, i.e. the
+    /// vector divided by the greatest common divisor of its components.
+    pub fn reduced(&self) -> Self {
+        match gcd(self.x, self.y) {
+            0 => *self,
+            divisor => *self / divisor,
+        }
+    }
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// The points from `a` to `b`, both included, forming the line of neighbouring cells closest to
+/// the straight line between them (Bresenham's algorithm).
+pub fn line(a: Point2, b: Point2) -> impl Iterator<Item = Point2> {
+    let (dx, dy) = ((b.x - a.x).abs(), -(b.y - a.y).abs());
+    let step = Vec2::new((b.x - a.x).signum(), (b.y - a.y).signum());
+    let mut error = dx + dy;
+    let mut next = Some(a);
+    std::iter::from_fn(move || {
+        let point = next?;
+        next = (point != b).then(|| {
+            let (mut moved, doubled) = (point, 2 * error);
+            if doubled >= dy {
+                error += dy;
+                moved.x += step.x;
+            }
+            if doubled <= dx {
+                error += dx;
+                moved.y += step.y;
+            }
+            moved
+        });
+        Some(point)
+    })
+}
+
+/// The points with integer coordinates exactly on the straight line from `a` to `b`, both
+/// included.
+pub fn lattice_points_on_line(a: Point2, b: Point2) -> impl Iterator<Item = Point2> {
+    let step = (b - a).reduced();
+    let count = gcd(b.x - a.x, b.y - a.y);
+    (0..=count).map(move |i| a + step * i)
+}
+
+impl<T: Add<Output = T>> Add<Vec2<T>> for Point2<T> {
+    type Output = Point2<T>;
+    fn add(self, rhs: Vec2<T>) -> Self::Output {
+        Point2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+impl<T: Sub<Output = T>> Sub<Vec2<T>> for Point2<T> {
+    type Output = Point2<T>;
+    fn sub(self, rhs: Vec2<T>) -> Self::Output {
+        Point2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+impl<T: Sub<Output = T>> Sub<Point2<T>> for Point2<T> {
+    type Output = Vec2<T>;
+    fn sub(self, rhs: Point2<T>) -> Self::Output {
+        Vec2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+impl<T: AddAssign> AddAssign<Vec2<T>> for Point2<T> {
+    fn add_assign(&mut self, rhs: Vec2<T>) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+impl<T: SubAssign> SubAssign<Vec2<T>> for Point2<T> {
+    fn sub_assign(&mut self, rhs: Vec2<T>) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl<T: Add<Output = T>> Add for Vec2<T> {
+    type Output = Vec2<T>;
+    fn add(self, rhs: Vec2<T>) -> Self::Output {
+        Vec2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+impl<T: Sub<Output = T>> Sub for Vec2<T> {
+    type Output = Vec2<T>;
+    fn sub(self, rhs: Vec2<T>) -> Self::Output {
+        Vec2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+impl<T: AddAssign> AddAssign for Vec2<T> {
+    fn add_assign(&mut self, rhs: Vec2<T>) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+impl<T: SubAssign> SubAssign for Vec2<T> {
+    fn sub_assign(&mut self, rhs: Vec2<T>) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+impl<T: Copy + Mul<Output = T>> Mul<T> for Vec2<T> {
+    type Output = Vec2<T>;
+    fn mul(self, rhs: T) -> Self::Output {
+        Vec2::new(self.x * rhs, self.y * rhs)
+    }
+}
+/// Divides both components, rounding towards zero like the integer division of `T`.
+impl<T: Copy + Div<Output = T>> Div<T> for Vec2<T> {
+    type Output = Vec2<T>;
+    fn div(self, rhs: T) -> Self::Output {
+        Vec2::new(self.x / rhs, self.y / rhs)
+    }
+}
+impl<T: Neg<Output = T>> Neg for Vec2<T> {
+    type Output = Vec2<T>;
+    fn neg(self) -> Self::Output {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+impl From<MatrixIdx> for Point2 {
+    fn from(idx: MatrixIdx) -> Self {
+        Point2::new(idx.col as i64, idx.row as i64)
+    }
+}
+/// Fails for points left of or above the grid, use [`crate::matrix::Matrix::get`] to also check
+/// the other edges.
+impl TryFrom<Point2> for MatrixIdx {
+    type Error = TryFromIntError;
+    fn try_from(point: Point2) -> Result<Self, Self::Error> {
+        Ok(MatrixIdx::new(point.y.try_into()?, point.x.try_into()?))
+    }
+}
+impl From<MatrixIdxOffset> for Vec2 {
+    fn from(offset: MatrixIdxOffset) -> Self {
+        Vec2::new(offset.cols, offset.rows)
+    }
+}
+impl From<Vec2> for MatrixIdxOffset {
+    fn from(vec: Vec2) -> Self {
+        MatrixIdxOffset::new(vec.y, vec.x)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::matrix::Direction;
+
+    #[test]
+    fn test_arithmetic() {
+        let p = Point2::new(3, -2);
+        let v = Vec2::new(4, 1);
+        assert_eq!(p + v, Point2::new(7, -1));
+        assert_eq!(p - v, Point2::new(-1, -3));
+        assert_eq!((p + v) - p, v);
+        assert_eq!(v * 3 - v, Vec2::new(8, 2));
+        assert_eq!(-v / 2, Vec2::new(-2, 0));
+        assert_eq!(v.dot(&Vec2::new(2, -3)), 5);
+        assert_eq!(v.cross(&Vec2::new(2, -3)), -14);
+        assert_eq!(Vec2::new(-3, 5).manhattan(), 8);
+        assert_eq!(Vec2::new(-3, 5).chebyshev(), 5);
+        assert_eq!(Point2::new(-1, 7).wrapped_in((3, 4)), Point2::new(3, 1));
+
+        let up = Vec2::from(Direction::Up.offset());
+        assert_eq!(up.rotate_right(), Vec2::from(Direction::Right.offset()));
+        assert_eq!(up.rotate_left(), Vec2::from(Direction::Left.offset()));
+        assert_eq!(up.rotate_right().rotate_right(), -up);
+    }
+
+    #[test]
+    fn test_lines() {
+        assert_eq!(Vec2::new(-6, 4).reduced(), Vec2::new(-3, 2));
+        assert_eq!(Vec2::new(0, -5).reduced(), Vec2::new(0, -1));
+        assert_eq!(Vec2::new(0, 0).reduced(), Vec2::new(0, 0));
+
+        let (a, b) = (Point2::new(1, 1), Point2::new(7, 5));
+        let lattice: Vec<_> = lattice_points_on_line(a, b).collect();
+        assert_eq!(lattice, [a, Point2::new(4, 3), b]);
+        assert_eq!(lattice_points_on_line(a, a).count(), 1);
+
+        let cells: Vec<_> = line(Point2::new(0, 0), Point2::new(5, -2)).collect();
+        assert_eq!(
+            cells,
+            [(0, 0), (1, 0), (2, -1), (3, -1), (4, -2), (5, -2)].map(|(x, y)| Point2::new(x, y))
+        );
+        assert!(line(a, b)
+            .zip(line(a, b).skip(1))
+            .all(|(p, q)| (q - p).chebyshev() == 1));
+        assert_eq!(line(b, b).collect::<Vec<_>>(), [b]);
+    }
+
+    #[test]
+    fn test_matrix_conversion() {
+        let idx = MatrixIdx::new(2, 5);
+        let point = Point2::from(idx);
+        assert_eq!(point, Point2::new(5, 2));
+        assert_eq!(MatrixIdx::try_from(point), Ok(idx));
+        assert!(MatrixIdx::try_from(point + Vec2::new(-6, 0)).is_err());
+        let offset = MatrixIdxOffset::new(-1, 3);
+        assert_eq!(MatrixIdxOffset::from(Vec2::from(offset)), offset);
+    }
+}
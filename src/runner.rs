@@ -0,0 +1,761 @@
+use std::fmt::{self, Display};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::answers::Answers;
+use crate::baseline::{self, Baselines, InputKey, COMPARISON_HEADER};
+use crate::bench::{self, BenchOptions, BenchRecord, TABLE_HEADER};
+use crate::days;
+use crate::error::Error;
+use crate::params::{self, Params, ParamsFile};
+use crate::scaffold;
+use crate::solution::{Answer, Part, Solution};
+use crate::util::{input_root, read_input, InputSource};
+
+/// Which days a `run`, `verify` or `bench` invocation covers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DaySelection {
+    All,
+    Day(i32),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunOptions {
+    pub days: DaySelection,
+    pub part: Option<Part>,
+    pub input: InputSource,
+    /// Parameter overrides given as `--param name=value`.
+    pub params: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerifyOptions {
+    pub days: DaySelection,
+    pub input_dir: PathBuf,
+}
+
+/// What `bench` does with the saved baselines.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BaselineArgs {
+    pub path: PathBuf,
+    /// Save the results as baseline of the current git revision.
+    pub save: bool,
+    /// Compare the results with the saved baselines.
+    pub compare: bool,
+    /// Revision to compare with instead of the most recently saved baseline.
+    pub against: Option<String>,
+    /// Slowdown of the median in percent that counts as a regression.
+    pub threshold: f64,
+}
+
+impl Default for BaselineArgs {
+    fn default() -> Self {
+        Self {
+            path: baseline::default_path(),
+            save: false,
+            compare: false,
+            against: None,
+            threshold: 10.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchArgs {
+    pub run: RunOptions,
+    pub options: BenchOptions,
+    /// Print the results as JSON instead of a table.
+    pub json: bool,
+    pub baselines: BaselineArgs,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run(RunOptions),
+    Verify(VerifyOptions),
+    Bench(BenchArgs),
+    /// Scaffold a new day from the template, creating its inputs below `input_dir`.
+    New {
+        day: i32,
+        input_dir: PathBuf,
+    },
+}
+
+pub const USAGE: &str =
+    "usage: aoc run <day|all> [--part <1|2>] [--input-dir <dir>] [--input <file|->] [--param <name=value>]
+       aoc verify <day|all> [--input-dir <dir>]
+       aoc bench <day|all> [run options] [--warmup <ms>] [--samples <n>] [--budget <ms>] [--json]
+                 [--save] [--compare] [--against <rev>] [--threshold <percent>] [--baselines <file>]
+       aoc new <day> [--input-dir <dir>]
+
+The input directory defaults to $AOC_INPUT_DIR or the crate's `inputs` folder.
+An input file (or `-` for stdin) can also be given after the day.
+Accepted answers are read from `answers.txt` next to the inputs, `verify` runs
+every input listed there and reports the parts whose answer changed.
+Days with constants that differ between the examples and the real input read them
+as parameters, overridden per input in `params.txt` next to the inputs or with `--param`.
+`bench` times parsing and solving separately and reports min, median, p95 and
+standard deviation of the run times. `--save` stores them as baseline of the
+current git revision, `--compare` fails if a median got slower than its latest
+baseline on the same input and parameters (or the one of `--against`) by more than
+the threshold, 10% by default, and if none of the timed stages has such a baseline.
+`new` creates a day from `src/bin/_day_temp.rs` together with its input files.";
+
+fn parse_days(arg: Option<&str>) -> Result<DaySelection, String> {
+    match arg {
+        Some("all") => Ok(DaySelection::All),
+        Some(day) => Ok(DaySelection::Day(
+            day.parse().map_err(|_| format!("invalid day '{day}'"))?,
+        )),
+        None => Err("missing day".to_string()),
+    }
+}
+
+fn parse_value<T: FromStr>(flag: &str, value: Option<&str>) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("missing value for {flag}"))?;
+    value
+        .parse()
+        .map_err(|_| format!("invalid value '{value}' for {flag}"))
+}
+
+impl Command {
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        let mut args = args.iter().map(|arg| arg.as_str());
+        match args.next() {
+            Some(command @ ("run" | "bench")) => {
+                let bench = command == "bench";
+                let days = parse_days(args.next())?;
+                let mut part = None;
+                let mut input_dir = None;
+                let mut input = None;
+                let mut params = Vec::new();
+                let mut options = BenchOptions::default();
+                let mut json = false;
+                let mut baselines = BaselineArgs::default();
+                while let Some(arg) = args.next() {
+                    match arg {
+                        "--part" | "-p" => {
+                            let number = args.next().ok_or("missing value for --part")?;
+                            part = Some(
+                                number
+                                    .parse()
+                                    .ok()
+                                    .and_then(Part::from_number)
+                                    .ok_or_else(|| format!("invalid part '{number}'"))?,
+                            );
+                        }
+                        "--input-dir" => {
+                            let dir = args.next().ok_or("missing value for --input-dir")?;
+                            input_dir = Some(dir.into());
+                        }
+                        "--input" | "-i" => {
+                            let source = args.next().ok_or("missing value for --input")?;
+                            input = Some(InputSource::from_arg(source));
+                        }
+                        "--param" => {
+                            let assignment = args.next().ok_or("missing value for --param")?;
+                            params.push(params::parse_assignment(assignment).ok_or_else(|| {
+                                format!("invalid parameter '{assignment}', expected name=value")
+                            })?);
+                        }
+                        "--warmup" if bench => {
+                            options.warmup = Duration::from_millis(parse_value(arg, args.next())?);
+                        }
+                        "--samples" if bench => options.samples = parse_value(arg, args.next())?,
+                        "--budget" if bench => {
+                            options.budget = Duration::from_millis(parse_value(arg, args.next())?);
+                        }
+                        "--json" if bench => json = true,
+                        "--save" if bench => baselines.save = true,
+                        "--compare" if bench => baselines.compare = true,
+                        "--against" if bench => {
+                            baselines.against = Some(parse_value(arg, args.next())?);
+                            baselines.compare = true;
+                        }
+                        "--threshold" if bench => {
+                            baselines.threshold = parse_value(arg, args.next())?;
+                        }
+                        "--baselines" if bench => {
+                            baselines.path = parse_value(arg, args.next())?;
+                        }
+                        other if other == "-" || !other.starts_with('-') => {
+                            input = Some(InputSource::from_arg(other));
+                        }
+                        other => return Err(format!("unexpected argument '{other}'")),
+                    }
+                }
+                if !params.is_empty() && days == DaySelection::All {
+                    return Err("parameters can only be set for a single day".into());
+                }
+                let input = match (input, input_dir) {
+                    (Some(_), Some(_)) => {
+                        return Err("--input-dir can not be combined with an input file".into())
+                    }
+                    (Some(_), None) if days == DaySelection::All => {
+                        return Err("an input file can only be used with a single day".into())
+                    }
+                    (Some(input), None) => input,
+                    (None, Some(dir)) => InputSource::Puzzle(dir),
+                    (None, None) => InputSource::default(),
+                };
+                let run = RunOptions {
+                    days,
+                    part,
+                    input,
+                    params,
+                };
+                if bench {
+                    Ok(Command::Bench(BenchArgs {
+                        run,
+                        options,
+                        json,
+                        baselines,
+                    }))
+                } else {
+                    Ok(Command::Run(run))
+                }
+            }
+            Some(command @ ("verify" | "new")) => {
+                let days = parse_days(args.next())?;
+                let mut input_dir = input_root();
+                while let Some(arg) = args.next() {
+                    match arg {
+                        "--input-dir" => {
+                            let dir = args.next().ok_or("missing value for --input-dir")?;
+                            input_dir = dir.into();
+                        }
+                        other => return Err(format!("unexpected argument '{other}'")),
+                    }
+                }
+                match (command, days) {
+                    ("verify", days) => Ok(Command::Verify(VerifyOptions { days, input_dir })),
+                    (_, DaySelection::Day(day)) => Ok(Command::New { day, input_dir }),
+                    (_, DaySelection::All) => Err("new needs a single day".to_string()),
+                }
+            }
+            Some(other) => Err(format!("unknown command '{other}'")),
+            None => Err("missing command".to_string()),
+        }
+    }
+}
+
+/// Answer and runtime of one part, together with the accepted answer if one is recorded.
+#[derive(Debug)]
+pub struct PartReport {
+    pub day: i32,
+    pub part: Part,
+    pub answer: Result<Answer, Error>,
+    pub elapsed: Duration,
+    pub expected: Option<String>,
+}
+
+impl PartReport {
+    /// Whether the answer matches the accepted one, `None` if none has been accepted yet.
+    pub fn is_correct(&self) -> Option<bool> {
+        let expected = self.expected.as_deref()?;
+        Some(matches!(&self.answer, Ok(answer) if answer.to_string() == expected))
+    }
+    pub fn is_success(&self) -> bool {
+        self.answer.is_ok() && self.is_correct() != Some(false)
+    }
+}
+
+impl Display for PartReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (day, part, elapsed) = (self.day, self.part.number(), self.elapsed);
+        match &self.answer {
+            Ok(answer) => write!(f, "day {day:>2} part {part}: {answer:<20} ({elapsed:.2?})")?,
+            Err(err) => return write!(f, "day {day:>2} part {part}: error: {err}"),
+        }
+        match (self.is_correct(), &self.expected) {
+            (Some(true), _) => write!(f, " ok"),
+            (Some(false), Some(expected)) => write!(f, " WRONG, expected {expected}"),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn solve_part(
+    solution: &dyn Solution,
+    part: Part,
+    content: &str,
+    params: &Params,
+    expected: Option<&str>,
+) -> PartReport {
+    let start = Instant::now();
+    let answer = solution.solve(part, content, params);
+    PartReport {
+        day: solution.day(),
+        part,
+        answer,
+        elapsed: start.elapsed(),
+        expected: expected.map(str::to_string),
+    }
+}
+
+/// The parameters of `solution` for the input `name` in `dir`, with `overrides` applied last.
+fn input_params(
+    solution: &dyn Solution,
+    location: Option<(&Path, &str)>,
+    overrides: &[(String, String)],
+) -> Result<Params, Error> {
+    let mut params = Params::new(solution.params());
+    if let Some((dir, name)) = location {
+        params.apply(ParamsFile::load(dir)?.for_input(name))?;
+    }
+    params.apply(overrides)?;
+    Ok(params)
+}
+
+/// Loads the input of `solution` and prints the answer and runtime of the requested parts.
+///
+/// Answers are checked against the accepted ones recorded next to the input, see [`Answers`].
+/// The parameters recorded for the input are overridden by `overrides`, see [`Params`].
+pub fn run(
+    solution: &dyn Solution,
+    part: Option<Part>,
+    input: &InputSource,
+    overrides: &[(String, String)],
+) -> Result<Vec<PartReport>, Error> {
+    let day = solution.day();
+    let content = input.load(day)?;
+    let location = input.location(day);
+    let location = location
+        .as_ref()
+        .map(|(dir, name)| (dir.as_path(), name.as_str()));
+    let params = input_params(solution, location, overrides)?;
+    let expected = match location {
+        Some((dir, name)) => Answers::load(dir)?.for_input(name).cloned(),
+        None => None,
+    };
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+    let mut reports = Vec::new();
+    for part in parts {
+        let expected = expected.as_ref().and_then(|expected| expected.get(part));
+        let report = solve_part(solution, part, &content, &params, expected);
+        println!("{report}");
+        reports.push(report);
+    }
+    Ok(reports)
+}
+
+fn select(days: DaySelection) -> Option<Vec<&'static dyn Solution>> {
+    match days {
+        DaySelection::All => Some(days::ALL.to_vec()),
+        DaySelection::Day(day) => {
+            let solution = days::get(day);
+            if solution.is_none() {
+                eprintln!("day {day} is not solved yet");
+            }
+            solution.map(|solution| vec![solution])
+        }
+    }
+}
+
+/// Runs every selected day, reporting days that fail instead of aborting.
+///
+/// Returns `false` if any of the selected days could not be run or gave a wrong answer.
+pub fn run_selection(options: &RunOptions) -> bool {
+    let Some(solutions) = select(options.days) else {
+        return false;
+    };
+    let mut success = true;
+    for solution in solutions {
+        match run(solution, options.part, &options.input, &options.params) {
+            Ok(reports) => success &= reports.iter().all(PartReport::is_success),
+            Err(err) => {
+                eprintln!("day {:>2}: {err}", solution.day());
+                success = false;
+            }
+        }
+    }
+    success
+}
+
+/// Runs every input that has accepted answers recorded for the day of `solution`.
+///
+/// Only the parts with an accepted answer are run, returns `None` if the day has no answers.
+pub fn verify(solution: &dyn Solution, root: &Path) -> Result<Option<Vec<PartReport>>, Error> {
+    let dir = root.join(format!("day{}", solution.day()));
+    let answers = Answers::load(&dir)?;
+    if answers.entries.is_empty() {
+        return Ok(None);
+    }
+    let mut reports = Vec::new();
+    for entry in &answers.entries {
+        let content = read_input(&dir.join(&entry.input))?;
+        let params = input_params(solution, Some((&dir, &entry.input)), &[])?;
+        for part in Part::ALL {
+            if let Some(expected) = entry.get(part) {
+                let report = solve_part(solution, part, &content, &params, Some(expected));
+                println!("{:<20} {report}", entry.input);
+                reports.push(report);
+            }
+        }
+    }
+    Ok(Some(reports))
+}
+
+/// Verifies every selected day and prints a summary, days without answers are skipped.
+///
+/// Returns `false` if any part no longer produces its accepted answer.
+pub fn verify_selection(options: &VerifyOptions) -> bool {
+    let Some(solutions) = select(options.days) else {
+        return false;
+    };
+    let (mut passed, mut failed, mut skipped) = (0, 0, 0);
+    for solution in solutions {
+        match verify(solution, &options.input_dir) {
+            Ok(Some(reports)) => {
+                let ok = reports.iter().filter(|report| report.is_success()).count();
+                passed += ok;
+                failed += reports.len() - ok;
+            }
+            Ok(None) => skipped += 1,
+            Err(err) => {
+                eprintln!("day {:>2}: {err}", solution.day());
+                failed += 1;
+            }
+        }
+    }
+    println!("{passed} passed, {failed} failed, {skipped} days without accepted answers");
+    failed == 0
+}
+
+/// Benchmarks every selected day and prints the results as table or JSON.
+///
+/// Returns `false` if any of the selected days could not be benchmarked.
+pub fn bench_selection(args: &BenchArgs) -> bool {
+    let Some(solutions) = select(args.run.days) else {
+        return false;
+    };
+    let parts = match args.run.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+    if !args.json {
+        println!("{TABLE_HEADER}");
+    }
+    let mut success = true;
+    let mut records = Vec::new();
+    for solution in solutions {
+        let day = solution.day();
+        let input = &args.run.input;
+        let location = input.location(day);
+        let location = location
+            .as_ref()
+            .map(|(dir, name)| (dir.as_path(), name.as_str()));
+        let result = input.load(day).and_then(|content| {
+            let params = input_params(solution, location, &args.run.params)?;
+            let key = InputKey::new(location.map(|(_, name)| name), &params);
+            let day_records = solution.bench(&parts, &content, &params, &args.options)?;
+            Ok((key, day_records))
+        });
+        match result {
+            Ok((key, day_records)) => {
+                if !args.json {
+                    day_records.iter().for_each(|record| println!("{record}"));
+                }
+                records.extend(day_records.into_iter().map(|record| (key.clone(), record)));
+            }
+            Err(err) => {
+                eprintln!("day {:>2}: {err}", solution.day());
+                success = false;
+            }
+        }
+    }
+    if args.json {
+        let records: Vec<_> = records.iter().map(|(_, record)| record.clone()).collect();
+        println!("{}", bench::to_json(&records));
+    }
+    match update_baselines(args, &records) {
+        Ok(no_regression) => success && no_regression,
+        Err(err) => {
+            eprintln!("{err}");
+            false
+        }
+    }
+}
+
+/// Compares `records` with and saves them to the baselines of their input as requested.
+///
+/// Returns `false` if a stage regressed. Comparison happens before saving, so the results are
+/// never compared with themselves.
+fn update_baselines(args: &BenchArgs, records: &[(InputKey, BenchRecord)]) -> Result<bool, Error> {
+    let BaselineArgs {
+        path,
+        save,
+        compare,
+        against,
+        threshold,
+    } = &args.baselines;
+    if !save && !compare {
+        return Ok(true);
+    }
+    // keep stdout valid JSON, the summary goes to stderr then
+    let print = |line: &dyn Display| {
+        if args.json {
+            eprintln!("{line}")
+        } else {
+            println!("{line}")
+        }
+    };
+    let mut baselines = Baselines::load(path)?;
+    let mut no_regression = true;
+    if *compare {
+        let comparisons = baseline::compare(records, &baselines, against.as_deref(), *threshold)?;
+        print(&"");
+        print(&COMPARISON_HEADER);
+        comparisons.iter().for_each(|comparison| print(comparison));
+        let regressions = comparisons.iter().filter(|c| c.regressed).count();
+        print(&format!(
+            "{regressions} of {} stages slower by more than {threshold}%, {} without baseline",
+            comparisons.len(),
+            records.len() - comparisons.len()
+        ));
+        no_regression = regressions == 0;
+    }
+    if *save {
+        let revision = baseline::git_revision();
+        baselines.record(&revision, records);
+        baselines.save(path)?;
+        print(&format!(
+            "saved baselines of {revision} to {}",
+            path.display()
+        ));
+    }
+    Ok(no_regression)
+}
+
+/// Entry point shared by the `aoc` binary and the per-day wrappers.
+pub fn main_with_args(args: &[String]) -> std::process::ExitCode {
+    let success = match Command::parse(args) {
+        Ok(Command::Run(options)) => run_selection(&options),
+        Ok(Command::Verify(options)) => verify_selection(&options),
+        Ok(Command::Bench(args)) => bench_selection(&args),
+        Ok(Command::New { day, input_dir }) => {
+            let crate_root = Path::new(env!("CARGO_MANIFEST_DIR"));
+            match scaffold::new_day(crate_root, &input_dir, day) {
+                Ok(created) => {
+                    for path in created {
+                        println!("wrote {}", path.display());
+                    }
+                    true
+                }
+                Err(err) => {
+                    eprintln!("{err}");
+                    false
+                }
+            }
+        }
+        Err(msg) => {
+            eprintln!("{msg}\n{USAGE}");
+            return std::process::ExitCode::from(2);
+        }
+    };
+    if success {
+        std::process::ExitCode::SUCCESS
+    } else {
+        std::process::ExitCode::FAILURE
+    }
+}
+
+/// Entry point of the thin `dayN` binaries, forwarding extra arguments like `--part 2`.
+pub fn main_for_day(day: i32) -> std::process::ExitCode {
+    let args: Vec<String> = ["run".to_string(), day.to_string()]
+        .into_iter()
+        .chain(std::env::args().skip(1))
+        .collect();
+    main_with_args(&args)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_command() {
+        assert_eq!(
+            Command::parse(&args(&["run", "7"])),
+            Ok(Command::Run(RunOptions {
+                days: DaySelection::Day(7),
+                part: None,
+                input: InputSource::default(),
+                params: Vec::new(),
+            }))
+        );
+        assert_eq!(
+            Command::parse(&args(&["run", "7", "--part", "2"])),
+            Ok(Command::Run(RunOptions {
+                days: DaySelection::Day(7),
+                part: Some(Part::Two),
+                input: InputSource::default(),
+                params: Vec::new(),
+            }))
+        );
+        assert_eq!(
+            Command::parse(&args(&["run", "all"])),
+            Ok(Command::Run(RunOptions {
+                days: DaySelection::All,
+                part: None,
+                input: InputSource::default(),
+                params: Vec::new(),
+            }))
+        );
+        assert!(Command::parse(&args(&["run", "7", "--part", "3"])).is_err());
+        assert!(Command::parse(&args(&["run"])).is_err());
+        assert!(Command::parse(&args(&["walk", "7"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_input_source() {
+        let input = |cmd: &[&str]| match Command::parse(&args(cmd)) {
+            Ok(Command::Run(options)) => Ok(options.input),
+            Ok(other) => Err(format!("expected a run command, got {other:?}")),
+            Err(err) => Err(err),
+        };
+        assert_eq!(
+            input(&["run", "7", "--input-dir", "/tmp/aoc"]),
+            Ok(InputSource::Puzzle("/tmp/aoc".into()))
+        );
+        assert_eq!(
+            input(&["run", "7", "--input", "day7.txt"]),
+            Ok(InputSource::File("day7.txt".into()))
+        );
+        assert_eq!(input(&["run", "7", "-"]), Ok(InputSource::Stdin));
+        assert_eq!(
+            input(&["run", "7", "-p", "1", "example.txt"]),
+            Ok(InputSource::File("example.txt".into()))
+        );
+        assert!(input(&["run", "all", "-"]).is_err());
+        assert!(input(&["run", "7", "-", "--input-dir", "/tmp"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_params() {
+        let params = |cmd: &[&str]| match Command::parse(&args(cmd)) {
+            Ok(Command::Run(options)) => Ok(options.params),
+            Ok(Command::Bench(args)) => Ok(args.run.params),
+            Ok(other) => Err(format!("expected a run command, got {other:?}")),
+            Err(err) => Err(err),
+        };
+        assert_eq!(
+            params(&["run", "14", "--param", "rows=7", "--param", "cols=11"]),
+            Ok(vec![
+                ("rows".into(), "7".into()),
+                ("cols".into(), "11".into())
+            ])
+        );
+        assert_eq!(
+            params(&["bench", "18", "--param", "size=7"]),
+            Ok(vec![("size".into(), "7".into())])
+        );
+        assert!(params(&["run", "14", "--param", "rows"]).is_err());
+        assert!(params(&["run", "all", "--param", "rows=7"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_verify() {
+        assert_eq!(
+            Command::parse(&args(&["verify", "all", "--input-dir", "/tmp/aoc"])),
+            Ok(Command::Verify(VerifyOptions {
+                days: DaySelection::All,
+                input_dir: "/tmp/aoc".into(),
+            }))
+        );
+        assert!(Command::parse(&args(&["verify", "7", "--part", "1"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        let run = RunOptions {
+            days: DaySelection::Day(2),
+            part: Some(Part::One),
+            input: InputSource::default(),
+            params: Vec::new(),
+        };
+        assert_eq!(
+            Command::parse(&args(&[
+                "bench",
+                "2",
+                "-p",
+                "1",
+                "--samples",
+                "10",
+                "--json"
+            ])),
+            Ok(Command::Bench(BenchArgs {
+                run,
+                options: BenchOptions {
+                    samples: 10,
+                    ..BenchOptions::default()
+                },
+                json: true,
+                baselines: BaselineArgs::default(),
+            }))
+        );
+        let baselines = |cmd: &[&str]| match Command::parse(&args(cmd)) {
+            Ok(Command::Bench(bench)) => Ok(bench.baselines),
+            Ok(other) => Err(format!("expected a bench command, got {other:?}")),
+            Err(err) => Err(err),
+        };
+        assert_eq!(
+            baselines(&["bench", "all", "--against", "abc", "--threshold", "5"]),
+            Ok(BaselineArgs {
+                compare: true,
+                against: Some("abc".into()),
+                threshold: 5.0,
+                ..BaselineArgs::default()
+            })
+        );
+        assert!(Command::parse(&args(&["bench", "2", "--samples", "many"])).is_err());
+        assert!(Command::parse(&args(&["run", "2", "--json"])).is_err());
+    }
+
+    #[test]
+    fn test_check_answer() {
+        let report = |answer, expected: Option<&str>| PartReport {
+            day: 1,
+            part: Part::One,
+            answer,
+            elapsed: Duration::ZERO,
+            expected: expected.map(str::to_string),
+        };
+        assert_eq!(report(Ok(Answer::Int(11)), None).is_correct(), None);
+        assert!(report(Ok(Answer::Int(11)), None).is_success());
+        assert!(report(Ok(Answer::Int(11)), Some("11")).is_success());
+        assert!(!report(Ok(Answer::Int(12)), Some("11")).is_success());
+        assert!(report(Ok(Answer::Coord(6, 1)), Some("6,1")).is_success());
+        assert!(!report(Err(Error::EmptyInput), Some("11")).is_success());
+    }
+
+    #[test]
+    fn test_parse_new() {
+        assert_eq!(
+            Command::parse(&args(&["new", "20", "--input-dir", "/tmp/aoc"])),
+            Ok(Command::New {
+                day: 20,
+                input_dir: "/tmp/aoc".into()
+            })
+        );
+        assert!(Command::parse(&args(&["new", "all"])).is_err());
+    }
+
+    #[test]
+    fn test_registry() {
+        for pair in days::ALL.windows(2) {
+            assert!(pair[0].day() < pair[1].day(), "days must be ordered");
+        }
+        for solution in days::ALL {
+            assert_eq!(days::get(solution.day()).unwrap().day(), solution.day());
+        }
+    }
+}
@@ -0,0 +1,236 @@
+use std::path::{Path, PathBuf};
+
+use crate::error::{Error, Result};
+use crate::util::read_input;
+
+/// The day template, a runnable binary so it keeps compiling as the library evolves.
+pub const TEMPLATE: &str = "src/bin/_day_temp.rs";
+
+const ANSWERS: &str = "# input part1 part2
+# test_input1.txt 11 31
+";
+
+/// Turns the template binary into the library module of `day`.
+///
+/// The template's `DayTemp` is renamed, its `DAY` set, the library is imported through `crate`
+/// and its `main` is dropped.
+pub fn render(template: &str, day: i32) -> Result<String> {
+    let missing = |what: &str| Error::Scaffold(format!("{TEMPLATE} has no {what}"));
+    let name = format!("Day{day:02}");
+    let template = template
+        .replacen("use adventofcode2024::", "use crate::", 1)
+        .replace("DayTemp", &name)
+        .replace("const DAY: i32 = 0;", &format!("const DAY: i32 = {day};"));
+    let main = template.find("\nfn main()").ok_or(missing("`fn main`"))?;
+    let main_end = template[main..]
+        .find("\n}\n")
+        .ok_or(missing("end of `fn main`"))?;
+    let rest = template[main + main_end + "\n}\n".len()..].trim_start_matches('\n');
+    Ok(format!("{}\n{rest}", &template[..=main]))
+}
+
+/// Adds `day` to the modules and the [`crate::days::ALL`] registry of `src/days/mod.rs`.
+pub fn register(mod_rs: &str, day: i32) -> Result<String> {
+    let module = format!("day{day:02}");
+    let declaration = format!("pub mod {module};");
+    let entry = format!("    &{module}::Day{day:02},");
+    if mod_rs.lines().any(|line| line == declaration) {
+        return Err(Error::Scaffold(format!("day {day} is already registered")));
+    }
+    let mut lines: Vec<String> = mod_rs.lines().map(str::to_string).collect();
+    insert_sorted(
+        &mut lines,
+        |line| line.starts_with("pub mod day"),
+        declaration,
+    )?;
+    insert_sorted(&mut lines, |line| line.starts_with("    &day"), entry)?;
+
+    // the registry is an array, so its length has to grow with it
+    let all = lines
+        .iter()
+        .position(|line| line.starts_with("pub const ALL: [&dyn Solution; "))
+        .ok_or(Error::Scaffold(
+            "src/days/mod.rs has no `ALL` registry".to_string(),
+        ))?;
+    let count = lines
+        .iter()
+        .filter(|line| line.starts_with("    &day"))
+        .count();
+    lines[all] = format!("pub const ALL: [&dyn Solution; {count}] = [");
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Inserts `new` into the sorted run of lines matching `is_item`.
+fn insert_sorted(
+    lines: &mut Vec<String>,
+    is_item: impl Fn(&str) -> bool,
+    new: String,
+) -> Result<()> {
+    let first = lines.iter().position(|line| is_item(line)).ok_or_else(|| {
+        Error::Scaffold(format!(
+            "no place found in src/days/mod.rs to add '{}'",
+            new.trim()
+        ))
+    })?;
+    let mut idx = first;
+    while idx < lines.len() && is_item(&lines[idx]) && lines[idx] < new {
+        idx += 1;
+    }
+    lines.insert(idx, new);
+    Ok(())
+}
+
+/// Creates the module, binary, registry entry and input files of a new day.
+///
+/// Refuses to touch an existing day, input files that already exist are kept as they are.
+/// Everything is prepared before the first file is written, and the files written so far are
+/// removed or restored if writing fails, so a day is created completely or not at all.
+/// Returns the created or changed files.
+pub fn new_day(crate_root: &Path, input_root: &Path, day: i32) -> Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        return Err(Error::Scaffold(format!(
+            "day {day} is not a puzzle day, expected 1 to 25"
+        )));
+    }
+    let module = crate_root.join(format!("src/days/day{day:02}.rs"));
+    let binary = crate_root.join(format!("src/bin/day{day}.rs"));
+    let mod_rs = crate_root.join("src/days/mod.rs");
+    for path in [&module, &binary] {
+        if path.exists() {
+            return Err(Error::Scaffold(format!(
+                "day {day} already exists: '{}'",
+                path.display()
+            )));
+        }
+    }
+    let source = render(&read_input(&crate_root.join(TEMPLATE))?, day)?;
+    let old_registry = read_input(&mod_rs)?;
+    let registry = register(&old_registry, day)?;
+
+    let mut files = vec![
+        (module, source),
+        (
+            binary,
+            format!(
+                "fn main() -> std::process::ExitCode {{\n    adventofcode2024::runner::main_for_day({day})\n}}\n"
+            ),
+        ),
+        (mod_rs.clone(), registry),
+    ];
+    let inputs = input_root.join(format!("day{day}"));
+    for (name, content) in [
+        ("input1.txt", ""),
+        ("test_input1.txt", ""),
+        (crate::answers::ANSWERS_FILE, ANSWERS),
+    ] {
+        let path = inputs.join(name);
+        if !path.exists() {
+            files.push((path, content.to_string()));
+        }
+    }
+
+    let write = |path: &Path, content: &str| {
+        std::fs::write(path, content).map_err(|source| Error::WriteFailed {
+            path: path.to_path_buf(),
+            source,
+        })
+    };
+    std::fs::create_dir_all(&inputs).map_err(|source| Error::WriteFailed {
+        path: inputs.clone(),
+        source,
+    })?;
+    for (written, (path, content)) in files.iter().enumerate() {
+        if let Err(err) = write(path, content) {
+            // undo the files written so far, `mod.rs` is restored
+            for (path, _) in files[..written].iter().rev() {
+                if *path == mod_rs {
+                    let _ = write(path, &old_registry);
+                } else {
+                    let _ = std::fs::remove_file(path);
+                }
+            }
+            return Err(err);
+        }
+    }
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const MOD_RS: &str = "use crate::solution::Solution;
+
+pub mod day01;
+pub mod day03;
+
+/// All solved days, ordered by day number.
+pub const ALL: [&dyn Solution; 2] = [
+    &day01::Day01,
+    &day03::Day03,
+];
+";
+
+    #[test]
+    fn test_register() {
+        let registry = register(MOD_RS, 2).unwrap();
+        assert!(registry.contains("pub mod day01;\npub mod day02;\npub mod day03;\n"));
+        assert!(registry.contains(
+            "[&dyn Solution; 3] = [\n    &day01::Day01,\n    &day02::Day02,\n    &day03::Day03,\n];"
+        ));
+        let registry = register(MOD_RS, 20).unwrap();
+        assert!(registry.contains("pub mod day03;\npub mod day20;\n"));
+        assert!(registry.contains("    &day03::Day03,\n    &day20::Day20,\n];"));
+        assert!(register(MOD_RS, 3).is_err());
+    }
+
+    #[test]
+    fn test_render() {
+        let template =
+            std::fs::read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join(TEMPLATE)).unwrap();
+        let source = render(&template, 7).unwrap();
+        assert!(source.starts_with("use crate::"));
+        assert!(source.contains("pub struct Day07;"));
+        assert!(source.contains("impl Puzzle for Day07 {\n    const DAY: i32 = 7;"));
+        assert!(source.contains("#[cfg(test)]\nmod test {"));
+        assert!(!source.contains("fn main"));
+        assert!(!source.contains("adventofcode2024"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_new_day_rollback() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("src/bin")).unwrap();
+        std::fs::create_dir_all(root.join("src/days")).unwrap();
+        std::fs::create_dir_all(root.join("inputs/day7")).unwrap();
+        std::fs::copy(
+            Path::new(env!("CARGO_MANIFEST_DIR")).join(TEMPLATE),
+            root.join(TEMPLATE),
+        )
+        .unwrap();
+        std::fs::write(root.join("src/days/mod.rs"), MOD_RS).unwrap();
+        // the answers are written last, through a link into a missing directory
+        let answers = root.join("inputs/day7").join(crate::answers::ANSWERS_FILE);
+        std::os::unix::fs::symlink(root.join("missing/answers.txt"), &answers).unwrap();
+
+        let inputs = root.join("inputs");
+        assert!(matches!(
+            new_day(&root, &inputs, 7),
+            Err(Error::WriteFailed { .. })
+        ));
+        assert!(!root.join("src/days/day07.rs").exists());
+        assert!(!root.join("src/bin/day7.rs").exists());
+        assert!(!root.join("inputs/day7/input1.txt").exists());
+        assert_eq!(read_input(&root.join("src/days/mod.rs")).unwrap(), MOD_RS);
+
+        std::fs::remove_file(&answers).unwrap();
+        assert_eq!(new_day(&root, &inputs, 7).unwrap().len(), 6);
+        assert!(matches!(
+            new_day(&root, &inputs, 7),
+            Err(Error::Scaffold(_))
+        ));
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,154 @@
+use std::fmt::{self, Display};
+
+use crate::bench::{self, BenchOptions, BenchRecord};
+use crate::error::Result;
+use crate::params::{Param, Params};
+
+/// One of the two parts every puzzle day consists of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> i32 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+    pub fn from_number(number: i32) -> Option<Self> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+/// The answer of a part, formatted the way the puzzle expects it to be submitted.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i64),
+    /// Free form text, e.g. a comma separated program output.
+    Str(String),
+    /// A position, submitted as `x,y`.
+    Coord(i64, i64),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(value) => f.pad(&value.to_string()),
+            Answer::Str(value) => f.pad(value),
+            Answer::Coord(x, y) => f.pad(&format!("{x},{y}")),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($int:ty),*) => {$(
+        impl From<$int> for Answer {
+            /// Counts too large for an `i64` keep their exact decimal form.
+            fn from(value: $int) -> Self {
+                i64::try_from(value).map_or_else(|_| Answer::Str(value.to_string()), Answer::Int)
+            }
+        }
+    )*};
+}
+answer_from_int!(i32, u32, i64, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Str(value)
+    }
+}
+
+/// A solved puzzle day, as registered in [`crate::days::ALL`].
+///
+/// This is the object safe view of a [`Puzzle`] the runner works with, both parts receive the
+/// raw puzzle input. Malformed input is reported as an [`Error`](crate::Error) instead of a panic.
+pub trait Solution {
+    fn day(&self) -> i32;
+    /// The parameters of the day with their defaults, see [`Puzzle::PARAMS`].
+    fn params(&self) -> &'static [Param];
+    fn part1(&self, content: &str, params: &Params) -> Result<Answer>;
+    fn part2(&self, content: &str, params: &Params) -> Result<Answer>;
+    /// Times parsing and each of `parts` on their own, see [`crate::bench`].
+    fn bench(
+        &self,
+        parts: &[Part],
+        content: &str,
+        params: &Params,
+        options: &BenchOptions,
+    ) -> Result<Vec<BenchRecord>>;
+
+    fn solve(&self, part: Part, content: &str, params: &Params) -> Result<Answer> {
+        match part {
+            Part::One => self.part1(content, params),
+            Part::Two => self.part2(content, params),
+        }
+    }
+}
+
+/// A puzzle day with parsing split from solving, so both can be measured separately.
+///
+/// The input is parsed once into [`Puzzle::Input`] and shared by both parts, every puzzle is a
+/// [`Solution`] through the blanket implementation below.
+pub trait Puzzle {
+    const DAY: i32;
+    /// Constants that differ between the examples and the real input, with their values for the
+    /// real input. They are passed to [`Puzzle::parse_with`].
+    const PARAMS: &'static [Param] = &[];
+    type Input;
+
+    fn parse(content: &str) -> Result<Self::Input>;
+    /// Parses the input using the given parameters, only days with [`Puzzle::PARAMS`] need this.
+    fn parse_with(content: &str, _params: &Params) -> Result<Self::Input> {
+        Self::parse(content)
+    }
+    fn part1(input: &Self::Input) -> Result<Answer>;
+    fn part2(input: &Self::Input) -> Result<Answer>;
+}
+
+impl<P: Puzzle> Solution for P {
+    fn day(&self) -> i32 {
+        P::DAY
+    }
+    fn params(&self) -> &'static [Param] {
+        P::PARAMS
+    }
+    fn part1(&self, content: &str, params: &Params) -> Result<Answer> {
+        P::part1(&P::parse_with(content, params)?)
+    }
+    fn part2(&self, content: &str, params: &Params) -> Result<Answer> {
+        P::part2(&P::parse_with(content, params)?)
+    }
+    fn bench(
+        &self,
+        parts: &[Part],
+        content: &str,
+        params: &Params,
+        options: &BenchOptions,
+    ) -> Result<Vec<BenchRecord>> {
+        bench::bench_puzzle::<P>(parts, content, params, options)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_answer_format() {
+        assert_eq!(Answer::from(42usize).to_string(), "42");
+        assert_eq!(Answer::from(-7).to_string(), "-7");
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(Answer::from("4,6,3".to_string()).to_string(), "4,6,3");
+        assert_eq!(Answer::Coord(6, 1).to_string(), "6,1");
+        assert_eq!(format!("{:<4}|", Answer::Int(7)), "7   |");
+    }
+}
@@ -0,0 +1,273 @@
+//! Finds the tokens hidden in otherwise meaningless input, like the instructions in corrupted
+//! memory.
+//!
+//! A token type declares a [`Tokenizer`] with one matcher per kind of token, see [`literal`],
+//! [`pattern`] and [`custom`]. The input is scanned once, trying the matchers at every offset,
+//! and anything between the tokens is skipped.
+use std::cell::OnceCell;
+use std::marker::PhantomData;
+
+use regex::{Captures, Regex};
+
+/// Matches one kind of token.
+pub trait IsToken<'a> {
+    type Token;
+    /// Returns the token starting exactly at `start` together with the offset it ends at.
+    fn match_at(&self, haystack: &'a str, start: usize) -> Option<(usize, Self::Token)>;
+    /// A regex matching wherever the token may start, which lets the tokenizer skip ahead to the
+    /// next candidate. With `None` the matcher is tried at every offset.
+    fn starts(&self) -> Option<String> {
+        None
+    }
+}
+
+/// Matches a fixed string, see [`literal`].
+pub struct Literal<T> {
+    literal: &'static str,
+    token: T,
+}
+
+/// Matches `literal` as `token`.
+pub fn literal<T: Clone>(literal: &'static str, token: T) -> Literal<T> {
+    Literal { literal, token }
+}
+
+impl<T: Clone> IsToken<'_> for Literal<T> {
+    type Token = T;
+    fn match_at(&self, haystack: &str, start: usize) -> Option<(usize, T)> {
+        haystack[start..]
+            .starts_with(self.literal)
+            .then(|| (start + self.literal.len(), self.token.clone()))
+    }
+    fn starts(&self) -> Option<String> {
+        Some(regex::escape(self.literal))
+    }
+}
+
+/// Matches a regex and converts its captures into a token, see [`pattern`].
+pub struct Pattern<T, F> {
+    source: String,
+    regex: Regex,
+    map: F,
+    token: PhantomData<fn() -> T>,
+}
+
+/// Matches `regex`, `map` turns the captures into the token's fields.
+///
+/// Matches for which `map` returns `None` are skipped as noise. The regex only sees the input
+/// from the offset it is tried at, so it cannot look behind it.
+///
+/// # Panics
+///
+/// If `regex` is not a valid regular expression.
+pub fn pattern<'a, T, F>(regex: &str, map: F) -> Pattern<T, F>
+where
+    F: Fn(&Captures<'a>) -> Option<T>,
+{
+    // anchored, so a failed attempt gives up after the first characters
+    let anchored = format!(r"\A(?:{regex})");
+    Pattern {
+        source: regex.to_string(),
+        regex: Regex::new(&anchored).unwrap_or_else(|err| panic!("invalid token regex: {err}")),
+        map,
+        token: PhantomData,
+    }
+}
+
+impl<'a, T, F> IsToken<'a> for Pattern<T, F>
+where
+    F: Fn(&Captures<'a>) -> Option<T>,
+{
+    type Token = T;
+    fn match_at(&self, haystack: &'a str, start: usize) -> Option<(usize, T)> {
+        let captures = self.regex.captures(&haystack[start..])?;
+        let end = start + captures.get(0)?.end();
+        Some((end, (self.map)(&captures)?))
+    }
+    fn starts(&self) -> Option<String> {
+        Some(self.source.clone())
+    }
+}
+
+/// Matches with a hand written function, see [`custom`].
+pub struct Custom<T, F> {
+    find: F,
+    token: PhantomData<fn() -> T>,
+}
+
+/// Matches with `find`, which returns the token starting exactly at the given offset together
+/// with the offset it ends at.
+pub fn custom<'a, T, F>(find: F) -> Custom<T, F>
+where
+    F: Fn(&'a str, usize) -> Option<(usize, T)>,
+{
+    Custom {
+        find,
+        token: PhantomData,
+    }
+}
+
+impl<'a, T, F> IsToken<'a> for Custom<T, F>
+where
+    F: Fn(&'a str, usize) -> Option<(usize, T)>,
+{
+    type Token = T;
+    fn match_at(&self, haystack: &'a str, start: usize) -> Option<(usize, T)> {
+        (self.find)(haystack, start)
+    }
+}
+
+/// Offset of the character after the one at `offset`, `None` at the end of `haystack`.
+fn next_char(haystack: &str, offset: usize) -> Option<usize> {
+    let len = haystack[offset..].chars().next()?.len_utf8();
+    Some(offset + len)
+}
+
+/// All kinds of tokens of a token type `T`, in order of precedence.
+pub struct Tokenizer<'a, T> {
+    matchers: Vec<Box<dyn IsToken<'a, Token = T> + 'a>>,
+    /// Finds the next offset any matcher may start at, `None` if a matcher has to be tried
+    /// everywhere. Built when tokenizing the first time.
+    starts: OnceCell<Option<Regex>>,
+}
+
+impl<'a, T> Default for Tokenizer<'a, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, T> Tokenizer<'a, T> {
+    pub fn new() -> Self {
+        Self {
+            matchers: Vec::new(),
+            starts: OnceCell::new(),
+        }
+    }
+    /// Adds a kind of token, it loses against earlier ones starting at the same offset.
+    pub fn with(mut self, matcher: impl IsToken<'a, Token = T> + 'a) -> Self {
+        self.matchers.push(Box::new(matcher));
+        self.starts = OnceCell::new();
+        self
+    }
+    /// Yields the tokens of `haystack` with their offset, in order and without overlaps.
+    pub fn tokenize<'t>(&'t self, haystack: &'a str) -> Tokens<'t, 'a, T> {
+        let starts = self.starts.get_or_init(|| {
+            let starts = self
+                .matchers
+                .iter()
+                .map(|matcher| matcher.starts().map(|regex| format!("(?:{regex})")))
+                .collect::<Option<Vec<_>>>()?;
+            Regex::new(&starts.join("|")).ok()
+        });
+        Tokens {
+            tokenizer: self,
+            starts: starts.as_ref(),
+            haystack,
+            offset: 0,
+        }
+    }
+}
+
+/// Iterator over the `(offset, token)` pairs of an input, see [`Tokenizer::tokenize`].
+///
+/// Walks the input once, at every offset the first matcher that matches wins and the walk
+/// continues after its token. Offsets no matcher may start at are skipped.
+pub struct Tokens<'t, 'a, T> {
+    tokenizer: &'t Tokenizer<'a, T>,
+    starts: Option<&'t Regex>,
+    haystack: &'a str,
+    offset: usize,
+}
+
+impl<T> Iterator for Tokens<'_, '_, T> {
+    type Item = (usize, T);
+    fn next(&mut self) -> Option<Self::Item> {
+        // past the end once the end has been tried, an empty token may still match there
+        let after = |offset| next_char(self.haystack, offset).unwrap_or(self.haystack.len() + 1);
+        while self.offset <= self.haystack.len() {
+            if let Some(starts) = self.starts {
+                self.offset = starts.find_at(self.haystack, self.offset)?.start();
+            }
+            let start = self.offset;
+            let found = self
+                .tokenizer
+                .matchers
+                .iter()
+                .find_map(|matcher| matcher.match_at(self.haystack, start));
+            match found {
+                Some((end, token)) => {
+                    // an empty token must not be found again at the same offset
+                    self.offset = if end > start { end } else { after(end) };
+                    return Some((start, token));
+                }
+                None => self.offset = after(start),
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Token<'a> {
+        Do,
+        Dont,
+        Mul(i32, i32),
+        Word(&'a str),
+    }
+
+    fn tokenizer<'a>() -> Tokenizer<'a, Token<'a>> {
+        Tokenizer::new()
+            .with(literal("do()", Token::Do))
+            .with(literal("don't()", Token::Dont))
+            .with(pattern(r"mul\((\d+),(\d+)\)", |cap| {
+                Some(Token::Mul(cap[1].parse().ok()?, cap[2].parse().ok()?))
+            }))
+            .with(custom(|haystack: &'a str, start| {
+                let word = haystack[start..].strip_prefix('#')?;
+                let len = word
+                    .find(|c: char| !c.is_ascii_alphabetic())
+                    .unwrap_or(word.len());
+                Some((start + 1 + len, Token::Word(&word[..len])))
+            }))
+    }
+
+    #[test]
+    fn test_tokenize() {
+        let tokenizer = tokenizer();
+        let tokens: Vec<_> = tokenizer
+            .tokenize("xmul(2,4)do()#ab!don't()_mul(99999999999,1)mul(5,5)mul(5,# do()")
+            .collect();
+        assert_eq!(
+            tokens,
+            vec![
+                (1, Token::Mul(2, 4)),
+                (9, Token::Do),
+                (13, Token::Word("ab")),
+                (17, Token::Dont),
+                (43, Token::Mul(5, 5)),
+                (57, Token::Word("")),
+                (59, Token::Do),
+            ]
+        );
+        assert_eq!(tokenizer.tokenize("no tokens").count(), 0);
+    }
+
+    #[test]
+    fn test_precedence() {
+        let tokenizer = Tokenizer::new()
+            .with(literal("ab", 1))
+            .with(literal("abc", 2))
+            .with(literal("bc", 3));
+        let tokens: Vec<_> = tokenizer.tokenize("abc bc").collect();
+        assert_eq!(tokens, vec![(0, 1), (4, 3)]);
+
+        let tokenizer = Tokenizer::new().with(pattern("b*", |cap| Some(cap[0].len())));
+        let tokens: Vec<_> = tokenizer.tokenize("ab").collect();
+        assert_eq!(tokens, vec![(0, 0), (1, 1), (2, 0)]);
+    }
+}
@@ -1,5 +1,9 @@
+use crate::error::Error;
 use nalgebra::{DMatrix, DVector, Vector3};
 use std::collections::{HashMap, HashSet};
+use std::num::ParseIntError;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 pub fn parse_vec3(string: &str) -> Vector3<i64> {
     Vector3::from_iterator(
         string
@@ -8,12 +12,96 @@ pub fn parse_vec3(string: &str) -> Vector3<i64> {
             .filter_map(|part| part.trim().parse::<i64>().ok()),
     )
 }
-pub fn load_file(day: i32, part: i32, test: bool) -> Result<String, std::io::Error> {
+/// Environment variable overriding the directory puzzle inputs are read from.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Returns the directory holding the `day{day}` input folders.
+///
+/// This is `$AOC_INPUT_DIR` if set and the `inputs` folder of this crate otherwise, so the
+/// binaries work independent of the directory they are started from.
+pub fn input_root() -> PathBuf {
+    std::env::var_os(INPUT_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs"))
+}
+pub fn input_path(root: &Path, day: i32, part: i32, test: bool) -> PathBuf {
     let teststr = if test { "test_" } else { "" };
+    root.join(format!("day{day}"))
+        .join(format!("{teststr}input{part}.txt"))
+}
+pub fn load_file(day: i32, part: i32, test: bool) -> Result<String, Error> {
+    read_input(&input_path(&input_root(), day, part, test))
+}
+pub fn read_input(path: &Path) -> Result<String, Error> {
+    std::fs::read_to_string(path).map_err(|source| Error::MissingInput {
+        path: path.into(),
+        source,
+    })
+}
+
+/// Where the puzzle input of a run is read from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputSource {
+    /// The day's `input1.txt` below the given input root.
+    Puzzle(PathBuf),
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Interprets a command line argument, where `-` selects stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(arg.into())
+        }
+    }
+    pub fn load(&self, day: i32) -> Result<String, Error> {
+        match self {
+            InputSource::Puzzle(root) => read_input(&input_path(root, day, 1, false)),
+            InputSource::File(path) => read_input(path),
+            InputSource::Stdin => {
+                std::io::read_to_string(std::io::stdin()).map_err(|source| Error::MissingInput {
+                    path: "-".into(),
+                    source,
+                })
+            }
+        }
+    }
+    /// The directory holding this input's accepted answers and parameters, and the input's name
+    /// within it.
+    ///
+    /// Input from stdin has no name, so it is never checked and always uses the default parameters.
+    pub fn location(&self, day: i32) -> Option<(PathBuf, String)> {
+        match self {
+            InputSource::Puzzle(root) => {
+                Some((root.join(format!("day{day}")), "input1.txt".to_string()))
+            }
+            InputSource::File(path) => {
+                let name = path.file_name()?.to_string_lossy().into_owned();
+                let dir = path.parent().unwrap_or(Path::new(""));
+                Some((dir.to_path_buf(), name))
+            }
+            InputSource::Stdin => None,
+        }
+    }
+}
 
-    let path = std::format!("inputs/day{day}/{teststr}input{part}.txt");
-    // println!("loading data from '{}'", path);
-    std::fs::read_to_string(path)
+impl Default for InputSource {
+    fn default() -> Self {
+        InputSource::Puzzle(input_root())
+    }
+}
+/// Parses a (possibly signed) integer, keeping the offending text in the error.
+pub fn parse_number<T>(value: &str) -> Result<T, Error>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    value.parse().map_err(|source| Error::InvalidNumber {
+        value: value.to_string(),
+        source,
+    })
 }
 
 struct SearchState {
@@ -125,13 +213,3 @@ where
         adjacency
     }
 }
-
-pub fn get_day() -> i32 {
-    let args: Vec<String> = std::env::args().collect();
-    let filename = std::path::Path::new(&args[0])
-        .file_stem()
-        .and_then(|name| name.to_str())
-        .unwrap();
-
-    filename[3..].parse().unwrap()
-}
@@ -0,0 +1,76 @@
+use std::path::Path;
+
+use adventofcode2024::days::{self, day11, day16, day17};
+use adventofcode2024::matrix::Direction;
+use adventofcode2024::runner;
+
+const MAZE: &str = "###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############";
+
+#[test]
+fn test_costmap() {
+    let (grid, start, end) = day16::parse(MAZE).unwrap();
+    let costmap = day16::compute_costmap(&grid, end);
+    let start = day16::State(start, Direction::Right);
+    assert_eq!(day16::lowest_cost(&costmap, &start).unwrap(), 7036);
+    assert_eq!(day16::part1(&(grid, start.0, end)).unwrap(), 7036);
+}
+
+#[test]
+fn test_cpu() {
+    let mut cpu = day17::Cpu::new(2024, 0, 0, 0);
+    let out = cpu.operate(&[0, 1, 5, 4, 3, 0]).unwrap();
+    assert_eq!(out, vec![4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0]);
+    assert_eq!(cpu.a, 0);
+
+    let input =
+        day17::parse("Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5,4,3,0")
+            .unwrap();
+    assert_eq!(day17::part1(&input).unwrap(), "4,6,3,5,6,3,5,2,1,0");
+}
+
+#[test]
+fn test_stones() {
+    let stones = day11::parse("125 17").unwrap();
+    let mut memoized = day11::MemoizedStones::new();
+    let count: usize = stones
+        .iter()
+        .map(|&stone| memoized.count_stones(stone, 25))
+        .sum();
+    assert_eq!(count, 55312);
+    assert_eq!(day11::part1(&stones, 25).unwrap(), count);
+}
+
+#[test]
+fn test_verify_examples() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs");
+    let mut verified = 0;
+    for solution in days::ALL {
+        if let Some(reports) = runner::verify(solution, &root).unwrap() {
+            assert!(
+                reports.iter().all(runner::PartReport::is_success),
+                "day {} no longer gives its accepted answers",
+                solution.day()
+            );
+            verified += reports.len();
+        }
+    }
+    assert!(
+        verified > 0,
+        "no accepted answers found in '{}'",
+        root.display()
+    );
+}